@@ -0,0 +1,54 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+/*!
+A compile-time benchmark for `macro_attr!`'s attribute and derivation splitting.
+
+This defines 300 items, each with a 40 line doc comment, nine built-in derivations and 24 macro derivations.  To measure it, time how long it takes to build:
+
+```text
+cargo bench --bench split_attrs --no-run
+```
+
+Before attribute and derivation splitting were batched, each of these items needed more than 128 levels of macro recursion to expand, exceeding the default limit.  They now expand within 48, which is set as the limit here; `tests/recursion_limit.rs` checks the same for the test suite.
+*/
+#![recursion_limit = "48"]
+#![allow(dead_code)]
+
+#[macro_use] extern crate macro_attr;
+
+include!("../tests/common/heavy_items.rs");
+
+heavy_items! {
+    I000 I001 I002 I003 I004 I005 I006 I007 I008 I009 I010 I011 I012 I013 I014
+    I015 I016 I017 I018 I019 I020 I021 I022 I023 I024 I025 I026 I027 I028 I029
+    I030 I031 I032 I033 I034 I035 I036 I037 I038 I039 I040 I041 I042 I043 I044
+    I045 I046 I047 I048 I049 I050 I051 I052 I053 I054 I055 I056 I057 I058 I059
+    I060 I061 I062 I063 I064 I065 I066 I067 I068 I069 I070 I071 I072 I073 I074
+    I075 I076 I077 I078 I079 I080 I081 I082 I083 I084 I085 I086 I087 I088 I089
+    I090 I091 I092 I093 I094 I095 I096 I097 I098 I099 I100 I101 I102 I103 I104
+    I105 I106 I107 I108 I109 I110 I111 I112 I113 I114 I115 I116 I117 I118 I119
+    I120 I121 I122 I123 I124 I125 I126 I127 I128 I129 I130 I131 I132 I133 I134
+    I135 I136 I137 I138 I139 I140 I141 I142 I143 I144 I145 I146 I147 I148 I149
+    I150 I151 I152 I153 I154 I155 I156 I157 I158 I159 I160 I161 I162 I163 I164
+    I165 I166 I167 I168 I169 I170 I171 I172 I173 I174 I175 I176 I177 I178 I179
+    I180 I181 I182 I183 I184 I185 I186 I187 I188 I189 I190 I191 I192 I193 I194
+    I195 I196 I197 I198 I199 I200 I201 I202 I203 I204 I205 I206 I207 I208 I209
+    I210 I211 I212 I213 I214 I215 I216 I217 I218 I219 I220 I221 I222 I223 I224
+    I225 I226 I227 I228 I229 I230 I231 I232 I233 I234 I235 I236 I237 I238 I239
+    I240 I241 I242 I243 I244 I245 I246 I247 I248 I249 I250 I251 I252 I253 I254
+    I255 I256 I257 I258 I259 I260 I261 I262 I263 I264 I265 I266 I267 I268 I269
+    I270 I271 I272 I273 I274 I275 I276 I277 I278 I279 I280 I281 I282 I283 I284
+    I285 I286 I287 I288 I289 I290 I291 I292 I293 I294 I295 I296 I297 I298 I299
+}
+
+fn main() {
+    assert_eq!(I000, Default::default());
+    assert_eq!(format!("{:?}", I299), "I299");
+}
@@ -77,6 +77,40 @@ This crate provides macros to derive the following methods for unitary variant e
 - `EnumFromInner!` derives `From<T>` for each variant's payload, assuming all variants are unary.
 - `EnumInnerAsTrait!` derives a method to return a borrowed pointer to the inner value, cast to a trait object.
 
+Using one of these on an enum with the wrong kind of variants results in a compile error naming the derivation, the enum and the offending variant; *e.g.* ``cannot derive `NextVariant!` for `Shape`: variant `Square` is not a unitary variant``.
+
+```compile_fail
+# #[macro_use] extern crate macro_attr;
+# #[macro_use] extern crate enum_derive;
+macro_attr! {
+    #[derive(NextVariant!)]
+    enum Shape { Circle, Square(u32) }
+}
+# fn main() {}
+```
+
+Likewise, using one on something other than an enum, or with arguments it doesn't accept, results in an error such as ``cannot derive `NextVariant!` for struct `Point`: only enums are supported``.
+
+```compile_fail
+# #[macro_use] extern crate macro_attr;
+# #[macro_use] extern crate enum_derive;
+macro_attr! {
+    #[derive(NextVariant!)]
+    struct Point(u32, u32);
+}
+# fn main() {}
+```
+
+```compile_fail
+# #[macro_use] extern crate macro_attr;
+# #[macro_use] extern crate enum_derive;
+macro_attr! {
+    #[derive(EnumDisplay!(lowercase))]
+    enum Shape { Circle, Square }
+}
+# fn main() {}
+```
+
 Both of the `IterVariant*!` macros accept a single deriving form.  Taking `IterVariants!` as an example, it must be invoked like so:
 
 ```rust
@@ -112,7 +146,28 @@ assert_eq!(&s[..], "42");
 
 The other macros take no arguments.
 
-The methods and iterator types generated will be public if the enum itself is public; otherwise, they will be private.
+The methods and iterator types generated will have the same visibility as the enum itself; *e.g.* deriving `IterVariants!` on a `pub(crate) enum` produces a `pub(crate) struct` iterator.
+
+Generic enums are supported: the derived implementations, along with any iterator types, carry over the enum's generic parameters, bounds and `where` clause.  Any bounds the payloads need (*e.g.* for `EnumInnerAsTrait!`) must be given on the enum itself.
+
+```rust
+# #[macro_use] extern crate macro_attr;
+# #[macro_use] extern crate enum_derive;
+use std::fmt::Debug;
+
+macro_attr! {
+    #[derive(EnumFromInner!, EnumInnerAsTrait!(as_debug -> &dyn Debug))]
+    enum Value<'a, T: Debug + 'a> {
+        Borrowed(&'a T),
+        Owned(Vec<T>),
+    }
+}
+
+# fn main() {
+let s = format!("{:?}", Value::from(vec![1, 2]).as_debug());
+assert_eq!(&s[..], "[1, 2]");
+# }
+```
 
 ## Using Without `macro_attr!`
 
@@ -140,6 +195,24 @@ IterVariants! { (Vars) enum ItAintRight { BabeNo, NoNo, BoyBoy } }
 # fn main() {}
 ```
 
+## Using Without `#[macro_use]`
+
+The macros in this crate don't depend on any other macros having been imported, so they can be imported individually (*e.g.* `#[macro_use(EnumDisplay, NextVariant)]`).  They can also be named by path, in which case the crate doesn't need to be imported with `#[macro_use]` at all:
+
+```rust
+#[macro_use] extern crate macro_attr;
+extern crate enum_derive;
+
+macro_attr! {
+    #[derive(Debug, enum_derive::EnumDisplay!, enum_derive::NextVariant!)]
+    enum Light { Red, Amber, Green }
+}
+
+# fn main() {
+assert_eq!(Light::Red.next_variant().unwrap().to_string(), "Amber");
+# }
+```
+
 ## Other Examples
 
 This shows how to use `Display` and `FromStr` to perform string round-tripping of enums.
@@ -207,6 +280,9 @@ assert_eq!(from_troll(&troll_number), Ok(number));
 
 #[cfg(not(feature = "std"))] extern crate core as std;
 
+#[doc(hidden)]
+pub extern crate macro_attr as __macro_attr;
+
 use std::fmt;
 
 #[doc(hidden)]
@@ -218,12 +294,12 @@ macro_rules! enum_derive_util {
     (@first_expr $head:expr) => {$head};
 
     (
-        @collect_unitary_variants ($callback:ident { $($args:tt)* }),
+        @collect_unitary_variants ($_name:ident, $callback:ident { $($args:tt)* }),
         ($(,)*) -> ($($var_names:ident,)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            $callback!{ $($args)* ($($var_names),*) }
+            $crate::$callback!{ $($args)* ($($var_names),*) }
         }
     };
 
@@ -231,7 +307,7 @@ macro_rules! enum_derive_util {
         @collect_unitary_variants $fixed:tt,
         (#[$_attr:meta] $($tail:tt)*) -> ($($var_names:tt)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @collect_unitary_variants $fixed,
             ($($tail)*) -> ($($var_names)*)
         }
@@ -241,26 +317,29 @@ macro_rules! enum_derive_util {
         @collect_unitary_variants $fixed:tt,
         ($var:ident $(= $_val:expr)*, $($tail:tt)*) -> ($($var_names:tt)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @collect_unitary_variants $fixed,
             ($($tail)*) -> ($($var_names)* $var,)
         }
     };
 
     (
-        @collect_unitary_variants ($name:ident),
-        ($var:ident $_struct:tt, $($tail:tt)*) -> ($($var_names:tt)*)
+        @collect_unitary_variants ($name:ident, $callback:ident $_args:tt),
+        ($var:ident $($_tail:tt)*) -> $_var_names:tt
     ) => {
-        const _error: () = "cannot parse unitary variants from enum with non-unitary variants";
+        compile_error!(concat!(
+            "cannot derive `", stringify!($callback), "!` for `", stringify!($name),
+            "`: variant `", stringify!($var), "` is not a unitary variant"
+        ));
     };
 
     (
-        @collect_unary_variants ($callback:ident { $($args:tt)* }),
+        @collect_unary_variants ($_name:ident, $callback:ident { $($args:tt)* }),
         ($(,)*) -> ($($out:tt)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            $callback!{ $($args)* ($($out)*) }
+            $crate::$callback!{ $($args)* ($($out)*) }
         }
     };
 
@@ -268,7 +347,7 @@ macro_rules! enum_derive_util {
         @collect_unary_variants $fixed:tt,
         (#[$_attr:meta] $($tail:tt)*) -> ($($out:tt)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @collect_unary_variants $fixed,
             ($($tail)*) -> ($($out)*)
         }
@@ -276,38 +355,79 @@ macro_rules! enum_derive_util {
 
     (
         @collect_unary_variants $fixed:tt,
-        ($var_name:ident($var_ty:ty), $($tail:tt)*) -> ($($out:tt)*)
+        ($var_name:ident($_vis:vis $var_ty:ty), $($tail:tt)*) -> ($($out:tt)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @collect_unary_variants $fixed,
             ($($tail)*) -> ($($out)* $var_name($var_ty),)
         }
     };
 
     (
-        @collect_unary_variants $fixed:tt,
-        ($var_name:ident(pub $var_ty:ty), $($tail:tt)*) -> ($($out:tt)*)
+        @collect_unary_variants ($name:ident, $callback:ident $_args:tt),
+        ($var:ident $($_tail:tt)*) -> $_out:tt
     ) => {
-        enum_derive_util! {
-            @collect_unary_variants $fixed,
-            ($($tail)*) -> ($($out)* $var_name($var_ty),)
+        compile_error!(concat!(
+            "cannot derive `", stringify!($callback), "!` for `", stringify!($name),
+            "`: variant `", stringify!($var), "` is not a single-field tuple variant"
+        ));
+    };
+
+    (
+        @parse $drv:ident, $arg:tt, $($item:tt)*
+    ) => {
+        $crate::__macro_attr::macro_attr_parse_item! {
+            ($crate::enum_derive_util! { @parsed $drv, $arg, }),
+            $($item)*
         }
     };
 
     (
-        @collect_unary_variants ($name:ident),
-        ($var:ident $_struct:tt, $($tail:tt)*) -> ($($_out:tt)*)
+        @parsed $drv:ident, $arg:tt,
+        attrs: $_attrs:tt,
+        vis: $vis:tt,
+        kind: enum,
+        name: $name:ident,
+        generics: $_generics:tt,
+        impl_generics: $impl_gen:tt,
+        ty_generics: $ty_gen:tt,
+        ty_params: $_ty_params:tt,
+        where: $where_:tt,
+        body: $body:tt
+    ) => {
+        $crate::$drv! { @parsed $arg $vis $name ($impl_gen $ty_gen $where_) $body }
+    };
+
+    (
+        @parsed $drv:ident, $_arg:tt,
+        attrs: $_attrs:tt,
+        vis: $_vis:tt,
+        kind: $kind:ident,
+        name: $name:ident,
+        $($_tail:tt)*
+    ) => {
+        compile_error!(concat!(
+            "cannot derive `", stringify!($drv), "!` for ", stringify!($kind), " `", stringify!($name),
+            "`: only enums are supported"
+        ));
+    };
+
+    (
+        @unsupported $drv:ident, $arg:tt, $name:ident
     ) => {
-        const _error: () = "cannot parse unary variants from enum with non-unary tuple variants";
+        compile_error!(concat!(
+            "cannot derive `", stringify!($drv), "!` for `", stringify!($name),
+            "`: unsupported derivation arguments `", stringify!($arg), "`"
+        ));
     };
 }
 
 #[macro_export]
 macro_rules! IterVariants {
     (
-        @expand ($($pub_:tt)*) $itername:ident, $name:ident ()
+        @expand ($vis:vis) $itername:ident, $name:ident (() () ()) ()
     ) => {
-        enum_derive_util! { @as_item $($pub_)* struct $itername; }
+        $crate::enum_derive_util! { @as_item $vis struct $itername; }
 
         impl ::std::iter::Iterator for $itername {
             type Item = $name;
@@ -322,11 +442,11 @@ macro_rules! IterVariants {
 
         impl ::std::iter::ExactSizeIterator for $itername { }
 
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
             impl $name {
                 #[allow(dead_code)]
-                $($pub_)* fn iter_variants() -> $itername {
+                $vis fn iter_variants() -> $itername {
                     $itername
                 }
             }
@@ -334,30 +454,73 @@ macro_rules! IterVariants {
     };
 
     (
-        @expand ($($pub_:tt)*) $itername:ident, $name:ident ($($var_names:ident),*)
+        @expand ($vis:vis) $itername:ident, $name:ident
+        (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ()
     ) => {
-        enum_derive_util! { @as_item $($pub_)* struct $itername(::std::option::Option<$name>); }
+        $crate::enum_derive_util! {
+            @as_item
+            $vis struct $itername<$($impl_gen)*>(::std::marker::PhantomData<$name<$($ty_gen)*>>)
+            where $($where_)*;
+        }
 
-        IterVariants! { @iter ($itername, $name), ($($var_names,)*) -> () () (0usize) }
+        $crate::enum_derive_util! {
+            @as_item
+            impl<$($impl_gen)*> ::std::iter::Iterator for $itername<$($ty_gen)*> where $($where_)* {
+                type Item = $name<$($ty_gen)*>;
+                fn next(&mut self) -> ::std::option::Option<Self::Item> {
+                    None
+                }
+
+                fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+                    (0, Some(0))
+                }
+            }
 
-        enum_derive_util! {
+            impl<$($impl_gen)*> ::std::iter::ExactSizeIterator for $itername<$($ty_gen)*> where $($where_)* { }
+
+            impl<$($impl_gen)*> $name<$($ty_gen)*> where $($where_)* {
+                #[allow(dead_code)]
+                $vis fn iter_variants() -> $itername<$($ty_gen)*> {
+                    $itername(::std::marker::PhantomData)
+                }
+            }
+        }
+    };
+
+    (
+        @expand ($vis:vis) $itername:ident, $name:ident
+        (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ($($var_names:ident),*)
+    ) => {
+        $crate::enum_derive_util! {
             @as_item
-            impl $name {
+            $vis struct $itername<$($impl_gen)*>(::std::option::Option<$name<$($ty_gen)*>>)
+            where $($where_)*;
+        }
+
+        $crate::IterVariants! {
+            @iter ($itername, $name, (($($impl_gen)*) ($($ty_gen)*) ($($where_)*))),
+            ($($var_names,)*) -> () () (0usize)
+        }
+
+        $crate::enum_derive_util! {
+            @as_item
+            impl<$($impl_gen)*> $name<$($ty_gen)*> where $($where_)* {
                 #[allow(dead_code)]
-                $($pub_)* fn iter_variants() -> $itername {
-                    $itername(::std::option::Option::Some(enum_derive_util!(@first_expr $($name::$var_names),+)))
+                $vis fn iter_variants() -> $itername<$($ty_gen)*> {
+                    $itername(::std::option::Option::Some($crate::enum_derive_util!(@first_expr $($name::$var_names),+)))
                 }
             }
         }
     };
 
     (
-        @iter ($itername:ident, $name:ident), () -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
+        @iter ($itername:ident, $name:ident, (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*))),
+        () -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl ::std::iter::Iterator for $itername {
-                type Item = $name;
+            impl<$($impl_gen)*> ::std::iter::Iterator for $itername<$($ty_gen)*> where $($where_)* {
+                type Item = $name<$($ty_gen)*>;
                 fn next(&mut self) -> ::std::option::Option<Self::Item> {
                     let next_item = match self.0 {
                         $($next_body)*
@@ -376,15 +539,15 @@ macro_rules! IterVariants {
                 }
             }
 
-            impl ::std::iter::ExactSizeIterator for $itername { }
+            impl<$($impl_gen)*> ::std::iter::ExactSizeIterator for $itername<$($ty_gen)*> where $($where_)* { }
         }
     };
 
     (
-        @iter ($itername:ident, $name:ident), ($a:ident, $b:ident, $($rest:tt)*) -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
+        @iter ($itername:ident, $name:ident, $gen:tt), ($a:ident, $b:ident, $($rest:tt)*) -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
     ) => {
-        IterVariants! {
-            @iter ($itername, $name), ($b, $($rest)*)
+        $crate::IterVariants! {
+            @iter ($itername, $name, $gen), ($b, $($rest)*)
             -> (
                 $($next_body)*
                 ::std::option::Option::Some($name::$a) => ::std::option::Option::Some($name::$b),
@@ -398,10 +561,10 @@ macro_rules! IterVariants {
     };
 
     (
-        @iter ($itername:ident, $name:ident), ($a:ident,) -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
+        @iter ($itername:ident, $name:ident, $gen:tt), ($a:ident,) -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
     ) => {
-        IterVariants! {
-            @iter ($itername, $name), ()
+        $crate::IterVariants! {
+            @iter ($itername, $name, $gen), ()
             -> (
                 $($next_body)*
                 ::std::option::Option::Some($name::$a) => ::std::option::Option::None,
@@ -414,29 +577,29 @@ macro_rules! IterVariants {
         }
     };
 
-    (($itername:ident) pub enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
+    (@parsed ($itername:ident) ($vis:vis) $name:ident $gen:tt ({ $($body:tt)* })) => {
+        $crate::enum_derive_util! {
             @collect_unitary_variants
-            (IterVariants { @expand (pub) $itername, $name }),
+            ($name, IterVariants { @expand ($vis) $itername, $name $gen }),
             ($($body)*,) -> ()
         }
     };
 
-    (($itername:ident) enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
-            @collect_unitary_variants
-            (IterVariants { @expand () $itername, $name }),
-            ($($body)*,) -> ()
-        }
+    (@parsed $arg:tt $_vis:tt $name:ident $_gen:tt $_body:tt) => {
+        $crate::enum_derive_util! { @unsupported IterVariants, $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::enum_derive_util! { @parse IterVariants, $arg, $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! IterVariantNames {
     (
-        @expand ($($pub_:tt)*) $itername:ident, $name:ident ()
+        @expand ($vis:vis) $itername:ident, $name:ident (() () ()) ()
     ) => {
-        enum_derive_util! { @as_item $($pub_)* struct $itername; }
+        $crate::enum_derive_util! { @as_item $vis struct $itername; }
 
         impl ::std::iter::Iterator for $itername {
             type Item = &'static str;
@@ -451,11 +614,11 @@ macro_rules! IterVariantNames {
 
         impl ::std::iter::ExactSizeIterator for $itername { }
 
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
             impl $name {
                 #[allow(dead_code)]
-                $($pub_)* fn iter_variant_names() -> $itername {
+                $vis fn iter_variant_names() -> $itername {
                     $itername
                 }
             }
@@ -463,29 +626,72 @@ macro_rules! IterVariantNames {
     };
 
     (
-        @expand ($($pub_:tt)*) $itername:ident, $name:ident ($($var_names:ident),*)
+        @expand ($vis:vis) $itername:ident, $name:ident
+        (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ()
+    ) => {
+        $crate::enum_derive_util! {
+            @as_item
+            $vis struct $itername<$($impl_gen)*>(::std::marker::PhantomData<$name<$($ty_gen)*>>)
+            where $($where_)*;
+        }
+
+        $crate::enum_derive_util! {
+            @as_item
+            impl<$($impl_gen)*> ::std::iter::Iterator for $itername<$($ty_gen)*> where $($where_)* {
+                type Item = &'static str;
+                fn next(&mut self) -> ::std::option::Option<Self::Item> {
+                    None
+                }
+
+                fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+                    (0, Some(0))
+                }
+            }
+
+            impl<$($impl_gen)*> ::std::iter::ExactSizeIterator for $itername<$($ty_gen)*> where $($where_)* { }
+
+            impl<$($impl_gen)*> $name<$($ty_gen)*> where $($where_)* {
+                #[allow(dead_code)]
+                $vis fn iter_variant_names() -> $itername<$($ty_gen)*> {
+                    $itername(::std::marker::PhantomData)
+                }
+            }
+        }
+    };
+
+    (
+        @expand ($vis:vis) $itername:ident, $name:ident
+        (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ($($var_names:ident),*)
     ) => {
-        enum_derive_util! { @as_item $($pub_)* struct $itername(::std::option::Option<$name>); }
+        $crate::enum_derive_util! {
+            @as_item
+            $vis struct $itername<$($impl_gen)*>(::std::option::Option<$name<$($ty_gen)*>>)
+            where $($where_)*;
+        }
 
-        IterVariantNames! { @iter ($itername, $name), ($($var_names,)*) -> () () (0usize) }
+        $crate::IterVariantNames! {
+            @iter ($itername, $name, (($($impl_gen)*) ($($ty_gen)*) ($($where_)*))),
+            ($($var_names,)*) -> () () (0usize)
+        }
 
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl $name {
+            impl<$($impl_gen)*> $name<$($ty_gen)*> where $($where_)* {
                 #[allow(dead_code)]
-                $($pub_)* fn iter_variant_names() -> $itername {
-                    $itername(::std::option::Option::Some(enum_derive_util!(@first_expr $($name::$var_names),+)))
+                $vis fn iter_variant_names() -> $itername<$($ty_gen)*> {
+                    $itername(::std::option::Option::Some($crate::enum_derive_util!(@first_expr $($name::$var_names),+)))
                 }
             }
         }
     };
 
     (
-        @iter ($itername:ident, $name:ident), () -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
+        @iter ($itername:ident, $name:ident, (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*))),
+        () -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl ::std::iter::Iterator for $itername {
+            impl<$($impl_gen)*> ::std::iter::Iterator for $itername<$($ty_gen)*> where $($where_)* {
                 type Item = &'static str;
                 fn next(&mut self) -> ::std::option::Option<Self::Item> {
                     let (next_state, result) = match self.0 {
@@ -506,15 +712,15 @@ macro_rules! IterVariantNames {
                 }
             }
 
-            impl ::std::iter::ExactSizeIterator for $itername { }
+            impl<$($impl_gen)*> ::std::iter::ExactSizeIterator for $itername<$($ty_gen)*> where $($where_)* { }
         }
     };
 
     (
-        @iter ($itername:ident, $name:ident), ($a:ident, $b:ident, $($rest:tt)*) -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
+        @iter ($itername:ident, $name:ident, $gen:tt), ($a:ident, $b:ident, $($rest:tt)*) -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
     ) => {
-        IterVariantNames! {
-            @iter ($itername, $name), ($b, $($rest)*)
+        $crate::IterVariantNames! {
+            @iter ($itername, $name, $gen), ($b, $($rest)*)
             -> (
                 $($next_body)*
                 ::std::option::Option::Some($name::$a)
@@ -529,10 +735,10 @@ macro_rules! IterVariantNames {
     };
 
     (
-        @iter ($itername:ident, $name:ident), ($a:ident,) -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
+        @iter ($itername:ident, $name:ident, $gen:tt), ($a:ident,) -> ($($next_body:tt)*) ($($size_body:tt)*) ($($count:tt)*)
     ) => {
-        IterVariantNames! {
-            @iter ($itername, $name), ()
+        $crate::IterVariantNames! {
+            @iter ($itername, $name, $gen), ()
             -> (
                 $($next_body)*
                 ::std::option::Option::Some($name::$a)
@@ -546,33 +752,33 @@ macro_rules! IterVariantNames {
         }
     };
 
-    (($itername:ident) pub enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
+    (@parsed ($itername:ident) ($vis:vis) $name:ident $gen:tt ({ $($body:tt)* })) => {
+        $crate::enum_derive_util! {
             @collect_unitary_variants
-            (IterVariantNames { @expand (pub) $itername, $name }),
+            ($name, IterVariantNames { @expand ($vis) $itername, $name $gen }),
             ($($body)*,) -> ()
         }
     };
 
-    (($itername:ident) enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
-            @collect_unitary_variants
-            (IterVariantNames { @expand () $itername, $name }),
-            ($($body)*,) -> ()
-        }
+    (@parsed $arg:tt $_vis:tt $name:ident $_gen:tt $_body:tt) => {
+        $crate::enum_derive_util! { @unsupported IterVariantNames, $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::enum_derive_util! { @parse IterVariantNames, $arg, $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NextVariant {
     (
-        @expand ($($pub_:tt)*) $name:ident ()
+        @expand ($vis:vis) $name:ident (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ()
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl $name {
+            impl<$($impl_gen)*> $name<$($ty_gen)*> where $($where_)* {
                 #[allow(dead_code)]
-                $($pub_)* fn next_variant(&self) -> ::std::option::Option<$name> {
+                $vis fn next_variant(&self) -> ::std::option::Option<$name<$($ty_gen)*>> {
                     loop {} // unreachable
                 }
             }
@@ -580,14 +786,14 @@ macro_rules! NextVariant {
     };
 
     (
-        @expand ($($pub_:tt)*) $name:ident ($($var_names:ident),*)
+        @expand ($vis:vis) $name:ident (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ($($var_names:ident),*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl $name {
+            impl<$($impl_gen)*> $name<$($ty_gen)*> where $($where_)* {
                 #[allow(dead_code)]
-                $($pub_)* fn next_variant(&self) -> ::std::option::Option<$name> {
-                    NextVariant!(@arms ($name, self), ($($var_names)*) -> ())
+                $vis fn next_variant(&self) -> ::std::option::Option<$name<$($ty_gen)*>> {
+                    $crate::NextVariant!(@arms ($name, self), ($($var_names)*) -> ())
                 }
             }
         }
@@ -596,7 +802,7 @@ macro_rules! NextVariant {
     (
         @arms ($name:ident, $self_:expr), ($a:ident) -> ($($body:tt)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_expr
             match *$self_ {
                 $($body)*
@@ -608,7 +814,7 @@ macro_rules! NextVariant {
     (
         @arms ($name:ident, $self_:expr), ($a:ident $b:ident $($rest:tt)*) -> ($($body:tt)*)
     ) => {
-        NextVariant! {
+        $crate::NextVariant! {
             @arms ($name, $self_), ($b $($rest)*)
             -> (
                 $($body)*
@@ -617,33 +823,33 @@ macro_rules! NextVariant {
         }
     };
 
-    (() pub enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
+    (@parsed () ($vis:vis) $name:ident $gen:tt ({ $($body:tt)* })) => {
+        $crate::enum_derive_util! {
             @collect_unitary_variants
-            (NextVariant { @expand (pub) $name }),
+            ($name, NextVariant { @expand ($vis) $name $gen }),
             ($($body)*,) -> ()
         }
     };
 
-    (() enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
-            @collect_unitary_variants
-            (NextVariant { @expand () $name }),
-            ($($body)*,) -> ()
-        }
+    (@parsed $arg:tt $_vis:tt $name:ident $_gen:tt $_body:tt) => {
+        $crate::enum_derive_util! { @unsupported NextVariant, $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::enum_derive_util! { @parse NextVariant, $arg, $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! PrevVariant {
     (
-        @expand ($($pub_:tt)*) $name:ident ()
+        @expand ($vis:vis) $name:ident (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ()
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl $name {
+            impl<$($impl_gen)*> $name<$($ty_gen)*> where $($where_)* {
                 #[allow(dead_code)]
-                $($pub_)* fn prev_variant(&self) -> ::std::option::Option<$name> {
+                $vis fn prev_variant(&self) -> ::std::option::Option<$name<$($ty_gen)*>> {
                     loop {} // unreachable
                 }
             }
@@ -651,14 +857,14 @@ macro_rules! PrevVariant {
     };
 
     (
-        @expand ($($pub_:tt)*) $name:ident ($($var_names:ident),*)
+        @expand ($vis:vis) $name:ident (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ($($var_names:ident),*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl $name {
+            impl<$($impl_gen)*> $name<$($ty_gen)*> where $($where_)* {
                 #[allow(dead_code)]
-                $($pub_)* fn prev_variant(&self) -> ::std::option::Option<$name> {
-                    PrevVariant!(@arms ($name, self), (::std::option::Option::None, $($var_names)*) -> ())
+                $vis fn prev_variant(&self) -> ::std::option::Option<$name<$($ty_gen)*>> {
+                    $crate::PrevVariant!(@arms ($name, self), (::std::option::Option::None, $($var_names)*) -> ())
                 }
             }
         }
@@ -667,7 +873,7 @@ macro_rules! PrevVariant {
     (
         @arms ($name:ident, $self_:expr), ($prev:expr, $a:ident) -> ($($body:tt)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_expr
             match *$self_ {
                 $($body)*
@@ -679,7 +885,7 @@ macro_rules! PrevVariant {
     (
         @arms ($name:ident, $self_:expr), ($prev:expr, $a:ident $($rest:tt)*) -> ($($body:tt)*)
     ) => {
-        PrevVariant! {
+        $crate::PrevVariant! {
             @arms ($name, $self_), (::std::option::Option::Some($name::$a), $($rest)*)
             -> (
                 $($body)*
@@ -688,31 +894,31 @@ macro_rules! PrevVariant {
         }
     };
 
-    (() pub enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
+    (@parsed () ($vis:vis) $name:ident $gen:tt ({ $($body:tt)* })) => {
+        $crate::enum_derive_util! {
             @collect_unitary_variants
-            (PrevVariant { @expand (pub) $name }),
+            ($name, PrevVariant { @expand ($vis) $name $gen }),
             ($($body)*,) -> ()
         }
     };
 
-    (() enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
-            @collect_unitary_variants
-            (PrevVariant { @expand () $name }),
-            ($($body)*,) -> ()
-        }
+    (@parsed $arg:tt $_vis:tt $name:ident $_gen:tt $_body:tt) => {
+        $crate::enum_derive_util! { @unsupported PrevVariant, $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::enum_derive_util! { @parse PrevVariant, $arg, $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! EnumDisplay {
     (
-        @expand $name:ident ()
+        @expand $name:ident (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ()
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl ::std::fmt::Display for $name {
+            impl<$($impl_gen)*> ::std::fmt::Display for $name<$($ty_gen)*> where $($where_)* {
                 fn fmt(&self, _: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
                     loop {} // unreachable
                 }
@@ -721,13 +927,13 @@ macro_rules! EnumDisplay {
     };
 
     (
-        @expand $name:ident ($($var_names:ident),*)
+        @expand $name:ident (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ($($var_names:ident),*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl ::std::fmt::Display for $name {
+            impl<$($impl_gen)*> ::std::fmt::Display for $name<$($ty_gen)*> where $($where_)* {
                 fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    EnumDisplay!(@arms ($name, self, f), ($($var_names)*) -> ())
+                    $crate::EnumDisplay!(@arms ($name, self, f), ($($var_names)*) -> ())
                 }
             }
         }
@@ -736,7 +942,7 @@ macro_rules! EnumDisplay {
     (
         @arms ($name:ident, $self_:expr, $f:ident), ($a:ident) -> ($($body:tt)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_expr
             match *$self_ {
                 $($body)*
@@ -748,7 +954,7 @@ macro_rules! EnumDisplay {
     (
         @arms ($name:ident, $self_:expr, $f:ident), ($a:ident $b:ident $($rest:tt)*) -> ($($body:tt)*)
     ) => {
-        EnumDisplay! {
+        $crate::EnumDisplay! {
             @arms ($name, $self_, $f), ($b $($rest)*)
             -> (
                 $($body)*
@@ -757,31 +963,31 @@ macro_rules! EnumDisplay {
         }
     };
 
-    (() pub enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
+    (@parsed () ($_vis:vis) $name:ident $gen:tt ({ $($body:tt)* })) => {
+        $crate::enum_derive_util! {
             @collect_unitary_variants
-            (EnumDisplay { @expand $name }),
+            ($name, EnumDisplay { @expand $name $gen }),
             ($($body)*,) -> ()
         }
     };
 
-    (() enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
-            @collect_unitary_variants
-            (EnumDisplay { @expand $name }),
-            ($($body)*,) -> ()
-        }
+    (@parsed $arg:tt $_vis:tt $name:ident $_gen:tt $_body:tt) => {
+        $crate::enum_derive_util! { @unsupported EnumDisplay, $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::enum_derive_util! { @parse EnumDisplay, $arg, $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! EnumFromStr {
     (
-        @expand ($($pub_:tt)*) $name:ident ()
+        @expand ($_vis:vis) $name:ident (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ()
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl ::std::str::FromStr for $name {
+            impl<$($impl_gen)*> ::std::str::FromStr for $name<$($ty_gen)*> where $($where_)* {
                 type Err = $crate::ParseEnumError;
 
                 fn from_str(_: &str) -> ::std::result::Result<Self, Self::Err> {
@@ -792,15 +998,15 @@ macro_rules! EnumFromStr {
     };
 
     (
-        @expand ($($pub_:tt)*) $name:ident ($($var_names:ident),*)
+        @expand ($_vis:vis) $name:ident (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) ($($var_names:ident),*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl ::std::str::FromStr for $name {
+            impl<$($impl_gen)*> ::std::str::FromStr for $name<$($ty_gen)*> where $($where_)* {
                 type Err = $crate::ParseEnumError;
 
                 fn from_str(s: &str) -> Result<Self, Self::Err> {
-                    EnumFromStr!(@arms ($name, s), ($($var_names)*) -> ())
+                    $crate::EnumFromStr!(@arms ($name, s), ($($var_names)*) -> ())
                 }
             }
         }
@@ -809,7 +1015,7 @@ macro_rules! EnumFromStr {
     (
         @arms ($name:ident, $s:ident), ($a:ident) -> ($($body:tt)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_expr
             match $s {
                 $($body)*
@@ -822,7 +1028,7 @@ macro_rules! EnumFromStr {
     (
         @arms ($name:ident, $s:ident), ($a:ident $b:ident $($rest:tt)*) -> ($($body:tt)*)
     ) => {
-        EnumFromStr! {
+        $crate::EnumFromStr! {
             @arms ($name, $s), ($b $($rest)*)
             -> (
                 $($body)*
@@ -831,20 +1037,20 @@ macro_rules! EnumFromStr {
         }
     };
 
-    (() pub enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
+    (@parsed () ($vis:vis) $name:ident $gen:tt ({ $($body:tt)* })) => {
+        $crate::enum_derive_util! {
             @collect_unitary_variants
-            (EnumFromStr { @expand (pub) $name }),
+            ($name, EnumFromStr { @expand ($vis) $name $gen }),
             ($($body)*,) -> ()
         }
     };
 
-    (() enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
-            @collect_unitary_variants
-            (EnumFromStr { @expand () $name }),
-            ($($body)*,) -> ()
-        }
+    (@parsed $arg:tt $_vis:tt $name:ident $_gen:tt $_body:tt) => {
+        $crate::enum_derive_util! { @unsupported EnumFromStr, $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::enum_derive_util! { @parse EnumFromStr, $arg, $($item)* }
     };
 }
 
@@ -872,62 +1078,64 @@ impl ::std::error::Error for ParseEnumError {
 #[macro_export]
 macro_rules! EnumFromInner {
     (
-        @expand $name:ident ($($var_names:ident($var_tys:ty),)*)
+        @expand $name:ident $gen:tt ($($var_names:ident($var_tys:ty),)*)
     ) => {
         $(
-            impl ::std::convert::From<$var_tys> for $name {
-                fn from(v: $var_tys) -> $name {
-                    $name::$var_names(v)
-                }
-            }
+            $crate::EnumFromInner! { @expand_var $name $gen $var_names($var_tys) }
         )*
     };
 
-    (() $(pub)* enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
+    (
+        @expand_var $name:ident (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)) $var_name:ident($var_ty:ty)
+    ) => {
+        impl<$($impl_gen)*> ::std::convert::From<$var_ty> for $name<$($ty_gen)*> where $($where_)* {
+            fn from(v: $var_ty) -> $name<$($ty_gen)*> {
+                $name::$var_name(v)
+            }
+        }
+    };
+
+    (@parsed () ($_vis:vis) $name:ident $gen:tt ({ $($body:tt)* })) => {
+        $crate::enum_derive_util! {
             @collect_unary_variants
-            (EnumFromInner { @expand $name }),
+            ($name, EnumFromInner { @expand $name $gen }),
             ($($body)*,) -> ()
         }
     };
-}
 
-#[macro_export]
-macro_rules! EnumInnerAsTrait {
-    (
-        @expand (pub $fn_name:ident -> &mut $tr:ty), $($tail:tt)*
-    ) => {
-        EnumInnerAsTrait! { @expand_inner (pub), $fn_name, (mut), $tr, $($tail)* }
+    (@parsed $arg:tt $_vis:tt $name:ident $_gen:tt $_body:tt) => {
+        $crate::enum_derive_util! { @unsupported EnumFromInner, $arg, $name }
     };
 
-    (
-        @expand (pub $fn_name:ident -> &$tr:ty), $($tail:tt)*
-    ) => {
-        EnumInnerAsTrait! { @expand_inner (pub), $fn_name, (), $tr, $($tail)* }
+    ($arg:tt $($item:tt)*) => {
+        $crate::enum_derive_util! { @parse EnumFromInner, $arg, $($item)* }
     };
+}
 
+#[macro_export]
+macro_rules! EnumInnerAsTrait {
     (
-        @expand ($fn_name:ident -> &mut $tr:ty), $($tail:tt)*
+        @expand ($vis:vis $fn_name:ident -> &mut $tr:ty), $($tail:tt)*
     ) => {
-        EnumInnerAsTrait! { @expand_inner (), $fn_name, (mut), $tr, $($tail)* }
+        $crate::EnumInnerAsTrait! { @expand_inner ($vis), $fn_name, (mut), $tr, $($tail)* }
     };
 
     (
-        @expand ($fn_name:ident -> &$tr:ty), $($tail:tt)*
+        @expand ($vis:vis $fn_name:ident -> &$tr:ty), $($tail:tt)*
     ) => {
-        EnumInnerAsTrait! { @expand_inner (), $fn_name, (), $tr, $($tail)* }
+        $crate::EnumInnerAsTrait! { @expand_inner ($vis), $fn_name, (), $tr, $($tail)* }
     };
 
     (
         @expand_inner
-        ($($vis:tt)*), $fn_name:ident, (mut), $tr:ty,
-        $ty_name:ident,
+        ($vis:vis), $fn_name:ident, (mut), $tr:ty,
+        $ty_name:ident, (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)),
         ($($var_names:ident($_var_tys:ty),)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl $ty_name {
-                $($vis)* fn $fn_name(&mut self) -> &mut $tr {
+            impl<$($impl_gen)*> $ty_name<$($ty_gen)*> where $($where_)* {
+                $vis fn $fn_name(&mut self) -> &mut $tr {
                     match *self {
                         $(
                             $ty_name::$var_names(ref mut v) => v as &mut $tr,
@@ -940,14 +1148,14 @@ macro_rules! EnumInnerAsTrait {
 
     (
         @expand_inner
-        ($($vis:tt)*), $fn_name:ident, (), $tr:ty,
-        $ty_name:ident,
+        ($vis:vis), $fn_name:ident, (), $tr:ty,
+        $ty_name:ident, (($($impl_gen:tt)*) ($($ty_gen:tt)*) ($($where_:tt)*)),
         ($($var_names:ident($_var_tys:ty),)*)
     ) => {
-        enum_derive_util! {
+        $crate::enum_derive_util! {
             @as_item
-            impl $ty_name {
-                $($vis)* fn $fn_name(&self) -> &$tr {
+            impl<$($impl_gen)*> $ty_name<$($ty_gen)*> where $($where_)* {
+                $vis fn $fn_name(&self) -> &$tr {
                     match *self {
                         $(
                             $ty_name::$var_names(ref v) => v as &$tr,
@@ -958,11 +1166,15 @@ macro_rules! EnumInnerAsTrait {
         }
     };
 
-    ($arg:tt $(pub)* enum $name:ident { $($body:tt)* }) => {
-        enum_derive_util! {
+    (@parsed $arg:tt ($_vis:vis) $name:ident $gen:tt ({ $($body:tt)* })) => {
+        $crate::enum_derive_util! {
             @collect_unary_variants
-            (EnumInnerAsTrait { @expand $arg, $name, }),
+            ($name, EnumInnerAsTrait { @expand $arg, $name, $gen, }),
             ($($body)*,) -> ()
         }
     };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::enum_derive_util! { @parse EnumInnerAsTrait, $arg, $($item)* }
+    };
 }
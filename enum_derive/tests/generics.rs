@@ -0,0 +1,57 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate enum_derive;
+#[macro_use] extern crate macro_attr;
+
+use std::fmt::Debug;
+
+macro_attr! {
+    #[derive(Debug, PartialEq,
+        IterVariants!(GetVariants), IterVariantNames!(GetVariantNames),
+        NextVariant!, PrevVariant!, EnumDisplay!, EnumFromStr!)]
+    pub enum Get where u8: Copy { Up, Down }
+}
+
+macro_attr! {
+    #[derive(IterVariants!(NeverVariants), IterVariantNames!(NeverVariantNames))]
+    pub enum Never where u8: Copy {}
+}
+
+macro_attr! {
+    #[derive(Debug, PartialEq, EnumFromInner!, EnumInnerAsTrait!(pub as_debug -> &dyn Debug))]
+    pub enum Value<'a, T: Debug + 'a> where T: Clone {
+        Borrowed(&'a T),
+        Owned(Vec<T>),
+    }
+}
+
+#[test]
+fn test_generic_unitary() {
+    let vars: GetVariants = Get::iter_variants();
+    let names: GetVariantNames = Get::iter_variant_names();
+    assert_eq!(&*vars.zip(names).collect::<Vec<_>>(), &[(Get::Up, "Up"), (Get::Down, "Down")]);
+    assert_eq!(Get::Up.next_variant(), Some(Get::Down));
+    assert_eq!(Get::Down.prev_variant(), Some(Get::Up));
+    assert_eq!(Get::Down.to_string(), "Down");
+    assert_eq!("Up".parse(), Ok(Get::Up));
+
+    let vars: NeverVariants = Never::iter_variants();
+    let names: NeverVariantNames = Never::iter_variant_names();
+    assert_eq!((vars.len(), names.len()), (0, 0));
+}
+
+#[test]
+fn test_generic_unary() {
+    let one = 1u8;
+    assert_eq!(Value::from(&one), Value::Borrowed(&1));
+    assert_eq!(Value::from(vec![2u8, 3]), Value::Owned::<u8>(vec![2, 3]));
+    assert_eq!(format!("{:?}", Value::from(&one).as_debug()), "1");
+    assert_eq!(format!("{:?}", Value::Owned::<u8>(vec![2, 3]).as_debug()), "[2, 3]");
+}
@@ -0,0 +1,30 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+#[macro_use(EnumDisplay, EnumFromStr, IterVariants, NextVariant)] extern crate enum_derive;
+
+// The derivations must not pick up helpers from the invoking crate.
+#[allow(unused_macros)]
+macro_rules! enum_derive_util {
+    ($($_tts:tt)*) => { compile_error!("used the wrong `enum_derive_util!`"); };
+}
+
+macro_attr! {
+    #[derive(Copy, Clone, Debug, PartialEq, EnumDisplay!, EnumFromStr!, IterVariants!(SizeVariants), NextVariant!)]
+    pub enum Size { Small, Medium, Large }
+}
+
+#[test]
+fn test_individual_imports() {
+    assert_eq!(Size::Medium.to_string(), "Medium");
+    assert_eq!("Large".parse::<Size>(), Ok(Size::Large));
+    assert_eq!(Size::iter_variants().count(), 3);
+    assert_eq!(Size::Small.next_variant(), Some(Size::Medium));
+}
@@ -0,0 +1,37 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+extern crate enum_derive;
+
+macro_attr! {
+    #[derive(Copy, Clone, Debug, PartialEq,
+        enum_derive::EnumDisplay!, enum_derive::EnumFromStr!,
+        enum_derive::IterVariants!(DirVariants), enum_derive::IterVariantNames!(DirVariantNames),
+        enum_derive::NextVariant!, enum_derive::PrevVariant!)]
+    pub enum Dir { North, East, South, West }
+}
+
+macro_attr! {
+    #[derive(Debug, ::enum_derive::EnumFromInner!, ::enum_derive::EnumInnerAsTrait!(pub as_debug -> &dyn std::fmt::Debug))]
+    pub enum Value { Int(i32), Text(&'static str) }
+}
+
+#[test]
+fn test_paths() {
+    assert_eq!(Dir::East.to_string(), "East");
+    assert_eq!("South".parse::<Dir>(), Ok(Dir::South));
+    assert_eq!(Dir::iter_variants().collect::<Vec<_>>(), vec![Dir::North, Dir::East, Dir::South, Dir::West]);
+    assert_eq!(Dir::iter_variant_names().collect::<Vec<_>>(), vec!["North", "East", "South", "West"]);
+    assert_eq!(Dir::West.next_variant(), None);
+    assert_eq!(Dir::East.prev_variant(), Some(Dir::North));
+
+    assert_eq!(format!("{:?}", Value::from(7).as_debug()), "7");
+    assert_eq!(format!("{:?}", Value::from("x").as_debug()), "\"x\"");
+}
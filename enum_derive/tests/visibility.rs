@@ -0,0 +1,39 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate enum_derive;
+#[macro_use] extern crate macro_attr;
+
+mod outer {
+    macro_attr! {
+        #[derive(Debug, PartialEq,
+            IterVariants!(GetVariants), IterVariantNames!(GetVariantNames),
+            NextVariant!, PrevVariant!, EnumDisplay!, EnumFromStr!)]
+        pub(crate) enum Get { Up, Down }
+    }
+
+    macro_attr! {
+        #[derive(Debug, PartialEq, EnumFromInner!,
+            EnumInnerAsTrait!(pub(crate) as_display -> &dyn std::fmt::Display))]
+        pub(crate) enum Value { U32(u32), U64(u64) }
+    }
+}
+
+#[test]
+fn test_restricted_visibility() {
+    use outer::{Get, GetVariants, GetVariantNames, Value};
+
+    let vars: GetVariants = Get::iter_variants();
+    let names: GetVariantNames = Get::iter_variant_names();
+    assert_eq!(&*vars.zip(names).collect::<Vec<_>>(), &[(Get::Up, "Up"), (Get::Down, "Down")]);
+    assert_eq!(Get::Up.next_variant(), Some(Get::Down));
+    assert_eq!(Get::Down.prev_variant(), Some(Get::Up));
+    assert_eq!("Down".parse(), Ok(Get::Down));
+    assert_eq!(format!("{}", Value::from(42u32).as_display()), "42");
+}
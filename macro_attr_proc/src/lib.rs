@@ -0,0 +1,589 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+/*!
+This crate provides the `#[macro_attr]` attribute, a procedural macro counterpart to the `macro_attr!` macro from the [`macro-attr`](https://crates.io/crates/macro-attr) crate.
+
+Rather than wrapping an item in `macro_attr! { ... }`, it can be annotated directly.  The same macro derivations are supported, so existing derivation macros such as `EnumDisplay!` or `NewtypeAdd!` work unchanged.
+
+## Compatibility
+
+`macro-attr-proc` is compatible with Rust 1.30 and higher.
+
+## Example
+
+```rust
+#[macro_use] extern crate enum_derive;
+#[macro_use] extern crate macro_attr_proc;
+
+#[macro_attr]
+#[derive(Copy, Clone, Debug, EnumDisplay!, EnumFromStr!)]
+enum Coffee { Espresso, Flat, Long }
+
+# fn main() {
+assert_eq!(format!("{}", Coffee::Flat), "Flat");
+assert_eq!("Long".parse::<Coffee>().unwrap() as u8, Coffee::Long as u8);
+# }
+```
+
+## Behaviour
+
+`#[macro_attr]` must be the first attribute on the item, so that it can see all the others.  It then does the same job as `macro_attr!`:
+
+- Derivations whose names end with `!` are invoked as macros, passing the derivation arguments and the item (without attributes), exactly as `macro_attr!` does.  Derivations written as `Name~!` are treated as ordinary procedural derivations.  The name of a derivation may be a path, as in `enum_derive::EnumDisplay!`.
+- Any `#[macro_attr(...)]` helper attributes on fields or variants are removed from the emitted item.
+- Derivations may be applied to any kind of item, not just a `struct`, `enum` or `union`.
+
+The compiler will not accept `#[name!(...)]` as an attribute, so macro attributes must instead be written as `#[macro_attr(name!(...))]` (or `#[macro_attr(name@!(...))]`).  This applies to macro attributes on fields and variants, too.  Because macro attributes have to re-enter the `macro_rules!` implementation after they run, an item with any macro attributes (on the item itself, or on its fields or variants) is handed over to `macro_attr!` in its entirety.  The same goes for an item with a `#[macro_attr_trace(...)]` marker or a `Name@!` derivation, since tracing and derivation contexts are implemented by `macro_attr!`.  This requires the `macro-attr` crate to be linked at the crate root, *i.e.* `#[macro_use] extern crate macro_attr;`.
+*/
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/**
+Processes macro derivations on the item it is attached to.  See the crate documentation for details.
+*/
+#[proc_macro_attribute]
+pub fn macro_attr(args: TokenStream, item: TokenStream) -> TokenStream {
+    if !args.is_empty() {
+        return error("`#[macro_attr]` does not take any arguments");
+    }
+
+    let tts: Vec<TokenTree> = item.into_iter().collect();
+    let (attrs, body) = split_attrs(&tts);
+
+    let mut bi_drvs: Vec<TokenStream> = vec![];
+    let mut user_drvs: Vec<(Vec<TokenTree>, TokenTree)> = vec![];
+    let mut others: Vec<TokenStream> = vec![];
+
+    for &attr in &attrs {
+        match classify_attr(attr) {
+            Attr::Derive(list) => {
+                for drv in split_commas(list) {
+                    match classify_derive(&drv) {
+                        Derive::Macro(path, args) => user_drvs.push((path, args)),
+                        Derive::Hybrid(path) => bi_drvs.push(path.into_iter().collect()),
+                        Derive::Context => return macro_attr_fallback(&attrs, body),
+                        Derive::Plain => bi_drvs.push(drv.into_iter().collect()),
+                    }
+                }
+            },
+            Attr::Macro(_) | Attr::Trace => return macro_attr_fallback(&attrs, body),
+            Attr::Hybrid(name, args) => {
+                let mut inner: Vec<TokenTree> = vec![TokenTree::Ident(name)];
+                inner.extend(args);
+                others.push(attr_tokens(inner.into_iter().collect()));
+            },
+            Attr::Plain => others.push(attr.iter().cloned().collect()),
+        }
+    }
+
+    let kind = item_kind(body);
+    if unwrap_macro_attrs(body.iter().cloned().collect()).1 {
+        return macro_attr_fallback(&attrs, body);
+    }
+
+    let has_fields = match kind {
+        Some(ref kind) => kind == "struct" || kind == "enum" || kind == "union",
+        None => false,
+    };
+
+    let mut out = TokenStream::new();
+
+    if !bi_drvs.is_empty() {
+        let mut list = TokenStream::new();
+        for (i, drv) in bi_drvs.into_iter().enumerate() {
+            if i > 0 {
+                list.extend(Some(TokenTree::Punct(Punct::new(',', Spacing::Alone))));
+            }
+            list.extend(drv);
+        }
+        let derive: TokenStream = vec![
+            TokenTree::Ident(Ident::new("derive", Span::call_site())),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, list)),
+        ].into_iter().collect();
+        out.extend(attr_tokens(derive));
+    }
+
+    out.extend(others);
+
+    let body: TokenStream = body.iter().cloned().collect();
+    if has_fields {
+        out.extend(strip_helpers(body.clone()));
+    } else {
+        out.extend(body.clone());
+    }
+
+    for (path, args) in user_drvs {
+        let mut input = TokenStream::new();
+        input.extend(Some(args));
+        input.extend(body.clone());
+        // The path keeps its own spans, since a `$crate` segment depends on them.
+        let span = path[path.len() - 1].span();
+        let invoke: TokenStream = vec![
+            TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+            TokenTree::Group(Group::new(Delimiter::Brace, input)),
+        ].into_iter().collect();
+        out.extend(path);
+        out.extend(invoke.into_iter().map(|mut tt| { tt.set_span(span); tt }));
+    }
+
+    out
+}
+
+/**
+Builds an identifier and passes it on to a callback.  This is an implementation detail of `macro_attr_ident!` in the `macro-attr` crate; see there for details.
+
+The input is `(krate) callback, case(parts...)`, optionally followed by `, more...`, where `krate` is the path to the `macro-attr` crate.  The output is `krate::macro_attr_callback! { callback, ident }`, or `krate::macro_attr_callback! { callback, ident, more... }`.
+*/
+#[doc(hidden)]
+#[proc_macro]
+pub fn macro_attr_ident_impl(input: TokenStream) -> TokenStream {
+    let mut tts: Vec<TokenTree> = input.into_iter().collect();
+    if tts.is_empty() || !is_group(&tts[0], Delimiter::Parenthesis) {
+        return error("macro_attr_ident!: expected the path to `macro-attr`; use `macro_attr_ident!` instead");
+    }
+    let krate = match tts.remove(0) {
+        TokenTree::Group(group) => group.stream(),
+        _ => unreachable!(),
+    };
+    if tts.len() < 4 || !is_punct(&tts[1], ',') || !is_group(&tts[3], Delimiter::Parenthesis) {
+        return error("macro_attr_ident!: expected `callback, case(parts...)`");
+    }
+    if tts.len() > 4 && !is_punct(&tts[4], ',') {
+        return error("macro_attr_ident!: expected `,` after `case(parts...)`");
+    }
+
+    let case = match tts[2] {
+        TokenTree::Ident(ref case) => case.to_string(),
+        _ => return error("macro_attr_ident!: expected one of `concat`, `snake`, `upper_snake` or `camel`"),
+    };
+
+    let parts = match tts[3] {
+        TokenTree::Group(ref group) => split_commas(group.stream()),
+        _ => unreachable!(),
+    };
+    let mut span = None;
+    let mut strs = vec![];
+    for part in parts {
+        match ident_part(&part) {
+            Some((s, part_span)) => {
+                span = span.or(part_span);
+                strs.push(s);
+            },
+            None => return error(&format!(
+                "macro_attr_ident!: expected an identifier, integer or string, found `{}`",
+                part.into_iter().collect::<TokenStream>()
+            )),
+        }
+    }
+
+    let words = || strs.iter().flat_map(|s| split_words(s)).collect::<Vec<_>>();
+    let name = match &*case {
+        "concat" => strs.concat(),
+        "snake" => words().iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("_"),
+        "upper_snake" => words().iter().map(|w| w.to_uppercase()).collect::<Vec<_>>().join("_"),
+        "camel" => words().iter().map(|w| capitalise(w)).collect::<Vec<_>>().concat(),
+        _ => return error(&format!(
+            "macro_attr_ident!: unknown case `{}`; expected one of `concat`, `snake`, `upper_snake` or `camel`",
+            case
+        )),
+    };
+
+    let valid = match name.chars().next() {
+        Some(c) => (c.is_alphabetic() || c == '_') && name.chars().all(|c| c.is_alphanumeric() || c == '_'),
+        None => false,
+    };
+    if !valid || name == "_" {
+        return error(&format!("macro_attr_ident!: `{}` is not a valid identifier", name));
+    }
+    if KEYWORDS.contains(&&*name) {
+        return error(&format!("macro_attr_ident!: `{}` is a keyword, and cannot be used as an identifier", name));
+    }
+
+    let mut args: Vec<TokenTree> = vec![
+        tts[0].clone(),
+        tts[1].clone(),
+        TokenTree::Ident(Ident::new(&name, span.unwrap_or_else(Span::call_site))),
+    ];
+    args.extend(tts[4..].iter().cloned());
+
+    let mut out = krate;
+    out.extend(vec![
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("macro_attr_callback", Span::call_site())),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Brace, args.into_iter().collect())),
+    ]);
+    out
+}
+
+/**
+Words which can't be used as identifiers.  This includes the reserved words, and those which are only keywords in later editions, since the identifier may be used in a crate of any edition.  Raw identifiers can't be created on all supported compilers, so these are rejected instead.
+*/
+const KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/**
+Converts one part of an identifier to a string.  Identifiers are used as-is (without the `r#` of a raw identifier), integers are written out, and strings contribute their contents.  The result also includes the span of the part, if it was an identifier.
+
+Parts forwarded by a `macro_rules!` macro may be wrapped in an invisible group, which is looked through.
+*/
+fn ident_part(part: &[TokenTree]) -> Option<(String, Option<Span>)> {
+    if part.len() != 1 {
+        return None;
+    }
+    match part[0] {
+        TokenTree::Ident(ref ident) => {
+            let s = ident.to_string();
+            Some((s.trim_start_matches("r#").to_string(), Some(ident.span())))
+        },
+        TokenTree::Literal(ref lit) => {
+            let s = lit.to_string();
+            if s.len() >= 2 && s.starts_with('"') && s.ends_with('"') {
+                Some((s[1..s.len() - 1].to_string(), None))
+            } else if s.chars().all(|c| c.is_ascii_digit()) {
+                Some((s, None))
+            } else {
+                None
+            }
+        },
+        TokenTree::Group(ref group) if group.delimiter() == Delimiter::None => {
+            let inner: Vec<TokenTree> = group.stream().into_iter().collect();
+            ident_part(&inner)
+        },
+        _ => None,
+    }
+}
+
+/**
+Splits a string into words, for case conversion.  Words are separated by underscores, and by changes in case: `FruitRock` and `fruit_rock` both contain `fruit` and `rock`, and `HTTPServer` contains `HTTP` and `Server`.
+*/
+fn split_words(s: &str) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut words = vec![];
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(word);
+                word = String::new();
+            }
+            continue;
+        }
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).map(|c| c.is_lowercase()).unwrap_or(false);
+            if !prev.is_uppercase() || next_lower {
+                words.push(word);
+                word = String::new();
+            }
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/**
+Upper-cases the first character of a word, and lower-cases the rest.
+*/
+fn capitalise(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars.flat_map(|c| c.to_lowercase())).collect(),
+        None => String::new(),
+    }
+}
+
+enum Attr {
+    /// `#[derive(...)]`; contains the list of derivations.
+    Derive(TokenStream),
+    /// `#[macro_attr(name!)]`, `#[macro_attr(name@!(...))]`, *etc.*; contains the equivalent `#[name!(...)]` attribute.
+    Macro(TokenStream),
+    /// `#[name~!]` or `#[name~!(...)]`; contains the name and arguments.
+    Hybrid(Ident, Vec<TokenTree>),
+    /// `#[macro_attr_trace(...)]`.
+    Trace,
+    /// Anything else.
+    Plain,
+}
+
+enum Derive {
+    /// `Name!` or `Name!(...)`; contains the path and the (possibly empty) argument group.
+    Macro(Vec<TokenTree>, TokenTree),
+    /// `Name~!`; contains the path.
+    Hybrid(Vec<TokenTree>),
+    /// `Name@!` or `Name@!(...)`.
+    Context,
+    /// Anything else.
+    Plain,
+}
+
+/**
+Splits the leading outer attributes off an item.  Each attribute is returned as its `#` and `[...]` tokens.
+*/
+fn split_attrs(tts: &[TokenTree]) -> (Vec<&[TokenTree]>, &[TokenTree]) {
+    let mut attrs = vec![];
+    let mut i = 0;
+    while i + 1 < tts.len() && is_punct(&tts[i], '#') && is_group(&tts[i + 1], Delimiter::Bracket) {
+        attrs.push(&tts[i..i + 2]);
+        i += 2;
+    }
+    (attrs, &tts[i..])
+}
+
+fn classify_attr(attr: &[TokenTree]) -> Attr {
+    let inner: Vec<TokenTree> = match attr[1] {
+        TokenTree::Group(ref group) => group.stream().into_iter().collect(),
+        _ => return Attr::Plain,
+    };
+
+    let name = match inner.first() {
+        Some(TokenTree::Ident(name)) => name.clone(),
+        _ => return Attr::Plain,
+    };
+
+    if name.to_string() == "derive" && inner.len() == 2 {
+        if let TokenTree::Group(ref group) = inner[1] {
+            if group.delimiter() == Delimiter::Parenthesis {
+                return Attr::Derive(group.stream());
+            }
+        }
+    }
+
+    if name.to_string() == "macro_attr" && inner.len() == 2 {
+        if let TokenTree::Group(ref group) = inner[1] {
+            let mac: Vec<TokenTree> = group.stream().into_iter().collect();
+            let is_mac = match mac.get(1) {
+                Some(tt) if is_punct(tt, '!') => true,
+                Some(tt) if is_punct(tt, '@') => mac.get(2).map(|tt| is_punct(tt, '!')).unwrap_or(false),
+                _ => false,
+            };
+            if group.delimiter() == Delimiter::Parenthesis && is_mac {
+                return Attr::Macro(attr_tokens(group.stream()));
+            }
+        }
+    }
+
+    if name.to_string() == "macro_attr_trace" {
+        return Attr::Trace;
+    }
+
+    match inner.get(1) {
+        Some(tt) if is_punct(tt, '~') && inner.get(2).map(|tt| is_punct(tt, '!')).unwrap_or(false) => {
+            Attr::Hybrid(name, inner[3..].to_vec())
+        },
+        _ => Attr::Plain,
+    }
+}
+
+fn classify_derive(drv: &[TokenTree]) -> Derive {
+    let path_len = drv.iter()
+        .take_while(|tt| match **tt {
+            TokenTree::Ident(_) => true,
+            ref tt => is_punct(tt, ':'),
+        })
+        .count();
+    let (path, rest) = drv.split_at(path_len);
+
+    match path.last() {
+        Some(&TokenTree::Ident(_)) => (),
+        _ => return Derive::Plain,
+    }
+
+    let path = path.to_vec();
+    match rest.len() {
+        1 if is_punct(&rest[0], '!') => {
+            let args = Group::new(Delimiter::Parenthesis, TokenStream::new());
+            Derive::Macro(path, TokenTree::Group(args))
+        },
+        2 if is_punct(&rest[0], '!') && is_group(&rest[1], Delimiter::Parenthesis) => {
+            Derive::Macro(path, rest[1].clone())
+        },
+        2 if is_punct(&rest[0], '~') && is_punct(&rest[1], '!') => Derive::Hybrid(path),
+        2 if is_punct(&rest[0], '@') && is_punct(&rest[1], '!') => Derive::Context,
+        3 if is_punct(&rest[0], '@') && is_punct(&rest[1], '!') && is_group(&rest[2], Delimiter::Parenthesis) => {
+            Derive::Context
+        },
+        _ => Derive::Plain,
+    }
+}
+
+/**
+Splits a derivation list on top-level commas, dropping any empty entries.
+*/
+fn split_commas(list: TokenStream) -> Vec<Vec<TokenTree>> {
+    let tts: Vec<TokenTree> = list.into_iter().collect();
+    tts.split(|tt| is_punct(tt, ','))
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| entry.to_vec())
+        .collect()
+}
+
+/**
+Works out what kind of item this is, skipping over any visibility.
+*/
+fn item_kind(body: &[TokenTree]) -> Option<String> {
+    let mut tts = body.iter().peekable();
+    while let Some(tt) = tts.next() {
+        match *tt {
+            TokenTree::Ident(ref ident) => {
+                let ident = ident.to_string();
+                if ident == "pub" {
+                    if let Some(TokenTree::Group(group)) = tts.peek() {
+                        if group.delimiter() == Delimiter::Parenthesis {
+                            tts.next();
+                        }
+                    }
+                    continue;
+                }
+                if ident == "crate" {
+                    continue;
+                }
+                return Some(ident);
+            },
+            // Visibilities forwarded from other macros can arrive wrapped in an invisible group.
+            TokenTree::Group(ref group) if group.delimiter() == Delimiter::None => continue,
+            _ => return None,
+        }
+    }
+    None
+}
+
+/**
+Removes `#[macro_attr(...)]` helper attributes from anywhere within the fields and variants of an item.
+*/
+fn strip_helpers(tts: TokenStream) -> TokenStream {
+    let tts: Vec<TokenTree> = tts.into_iter().collect();
+    let mut out = vec![];
+    let mut i = 0;
+    while i < tts.len() {
+        if i + 1 < tts.len() && is_punct(&tts[i], '#') && is_helper(&tts[i + 1]) {
+            i += 2;
+            continue;
+        }
+        out.push(match tts[i] {
+            TokenTree::Group(ref group) if group.delimiter() != Delimiter::Bracket => {
+                let mut new_group = Group::new(group.delimiter(), strip_helpers(group.stream()));
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group)
+            },
+            ref tt => tt.clone(),
+        });
+        i += 1;
+    }
+    out.into_iter().collect()
+}
+
+fn is_helper(tt: &TokenTree) -> bool {
+    match *tt {
+        TokenTree::Group(ref group) if group.delimiter() == Delimiter::Bracket => {
+            match group.stream().into_iter().next() {
+                Some(TokenTree::Ident(ref ident)) => ident.to_string() == "macro_attr",
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+/**
+Replaces any `#[macro_attr(name!(...))]` attributes on fields and variants with `#[name!(...)]`.  Also returns whether there were any.
+*/
+fn unwrap_macro_attrs(tts: TokenStream) -> (TokenStream, bool) {
+    let tts: Vec<TokenTree> = tts.into_iter().collect();
+    let mut out = TokenStream::new();
+    let mut found = false;
+    let mut i = 0;
+    while i < tts.len() {
+        if i + 1 < tts.len() && is_punct(&tts[i], '#') && is_group(&tts[i + 1], Delimiter::Bracket) {
+            if let Attr::Macro(mac) = classify_attr(&tts[i..i + 2]) {
+                out.extend(mac);
+                found = true;
+                i += 2;
+                continue;
+            }
+        }
+        match tts[i] {
+            TokenTree::Group(ref group) if group.delimiter() != Delimiter::Bracket => {
+                let (stream, inner_found) = unwrap_macro_attrs(group.stream());
+                let mut new_group = Group::new(group.delimiter(), stream);
+                new_group.set_span(group.span());
+                out.extend(Some(TokenTree::Group(new_group)));
+                found = found || inner_found;
+            },
+            ref tt => out.extend(Some(tt.clone())),
+        }
+        i += 1;
+    }
+    (out, found)
+}
+
+/**
+Hands the whole item over to `macro_attr!`, unwrapping any macro attributes.
+*/
+fn macro_attr_fallback(attrs: &[&[TokenTree]], body: &[TokenTree]) -> TokenStream {
+    let mut item = TokenStream::new();
+    for &attr in attrs {
+        match classify_attr(attr) {
+            Attr::Macro(mac) => item.extend(mac),
+            _ => item.extend(attr.iter().cloned()),
+        }
+    }
+    item.extend(unwrap_macro_attrs(body.iter().cloned().collect()).0);
+
+    // `#[macro_attr]` shadows `macro_attr!`, so the latter has to be named by path.
+    vec![
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("macro_attr", Span::call_site())),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("macro_attr", Span::call_site())),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Brace, item)),
+    ].into_iter().collect()
+}
+
+/**
+Wraps the contents of an attribute in `#[...]`.
+*/
+fn attr_tokens(inner: TokenStream) -> TokenStream {
+    vec![
+        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Bracket, inner)),
+    ].into_iter().collect()
+}
+
+fn error(msg: &str) -> TokenStream {
+    format!("compile_error!({:?});", msg).parse().unwrap()
+}
+
+fn is_punct(tt: &TokenTree, ch: char) -> bool {
+    match *tt {
+        TokenTree::Punct(ref punct) => punct.as_char() == ch,
+        _ => false,
+    }
+}
+
+fn is_group(tt: &TokenTree, delim: Delimiter) -> bool {
+    match *tt {
+        TokenTree::Group(ref group) => group.delimiter() == delim,
+        _ => false,
+    }
+}
@@ -0,0 +1,97 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate enum_derive;
+#[macro_use] extern crate macro_attr_proc;
+#[macro_use] extern crate newtype_derive;
+
+trait Labels {
+    fn labels() -> Vec<&'static str>;
+}
+
+macro_rules! Labels {
+    (() $_vis:vis struct $name:ident { $($(#[$($attrs:tt)*])* $fvis:vis $fname:ident: $fty:ty),* $(,)* }) => {
+        impl Labels for $name {
+            fn labels() -> Vec<&'static str> {
+                vec![$(Labels!(@label $fname, $(#[$($attrs)*])*)),*]
+            }
+        }
+    };
+
+    (@label $fname:ident,) => { stringify!($fname) };
+    (@label $fname:ident, #[macro_attr(rename = $label:expr)] $($_tail:tt)*) => { $label };
+    (@label $fname:ident, #[$($_attr:tt)*] $($tail:tt)*) => { Labels!(@label $fname, $($tail)*) };
+}
+
+#[macro_attr]
+#[derive(Copy, Clone, Debug, PartialEq, EnumDisplay!, EnumFromStr!, IterVariants!(GetVariants))]
+pub enum Get { Up, Down, AllAround }
+
+#[macro_attr]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(NewtypeFrom!, NewtypeAdd!, NewtypeAdd!(i32), NewtypeNeg!, NewtypeDisplay!)]
+pub(crate) struct Happy(i32);
+
+#[macro_attr]
+/// Doc comments and other attributes are kept.
+#[derive(Clone, Debug, Labels!)]
+#[allow(dead_code)]
+struct Renamed {
+    #[macro_attr(rename = "first")]
+    a: u8,
+    /// Documented.
+    b: u8,
+}
+
+macro_rules! Twice {
+    (() $_vis:vis fn $name:ident () -> $ty:ty { $($body:tt)* }) => {
+        fn twice() -> $ty { 2 * $name() }
+    };
+}
+
+#[macro_attr]
+#[derive(Twice!)]
+fn once() -> i32 { 21 }
+
+#[macro_attr]
+#[derive(Copy, Clone, Debug, PartialEq, enum_derive::NextVariant!, ::enum_derive::PrevVariant!)]
+pub enum Dir { Left, Right }
+
+#[test]
+fn test_enum_derive() {
+    assert_eq!(Get::iter_variants().collect::<Vec<_>>(), vec![Get::Up, Get::Down, Get::AllAround]);
+    assert_eq!(format!("{}", Get::AllAround), "AllAround");
+    assert_eq!("Down".parse::<Get>(), Ok(Get::Down));
+}
+
+#[test]
+fn test_derive_paths() {
+    assert_eq!(Dir::Left.next_variant(), Some(Dir::Right));
+    assert_eq!(Dir::Left.prev_variant(), None);
+}
+
+#[test]
+fn test_newtype_derive() {
+    let a = Happy::from(6);
+    let b = -(a + Happy(1)) + 2;
+    assert_eq!(b, Happy(-5));
+    assert_eq!(format!("{}", b), "-5");
+}
+
+#[test]
+fn test_helpers() {
+    assert_eq!(Renamed::labels(), vec!["first", "b"]);
+    assert_eq!(format!("{:?}", Renamed { a: 1, b: 2 }), "Renamed { a: 1, b: 2 }");
+}
+
+#[test]
+fn test_fn_derive() {
+    assert_eq!(once(), 21);
+    assert_eq!(twice(), 42);
+}
@@ -0,0 +1,36 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+#[macro_use] extern crate macro_attr_proc;
+
+macro_rules! Name {
+    (() $_vis:vis struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_rules! with_name {
+    ((), then $cb:tt, #[derive($($drvs:tt)*)] $($it:tt)*) => {
+        macro_attr_callback! { $cb, #[derive($($drvs)* Name!)] $($it)* }
+    };
+}
+
+#[macro_attr]
+#[derive(Debug)]
+#[macro_attr(with_name@!)]
+struct Edited;
+
+#[test]
+fn test_mac_attr_derives() {
+    assert_eq!(format!("{:?}", Edited), "Edited");
+    assert_eq!(Edited::name(), "Edited");
+}
@@ -0,0 +1,96 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+#[macro_use] extern crate macro_attr_proc;
+
+macro_rules! Name {
+    (() $_vis:vis struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_rules! rename_to {
+    (
+        ($new_name:ident),
+        then $cb:tt,
+        $(#[$($attrs:tt)*])*
+        $vis:vis struct $_old_name:ident $($tail:tt)*
+    ) => {
+        macro_attr_callback! {
+            $cb,
+            $(#[$($attrs)*])*
+            $vis struct $new_name $($tail)*
+        }
+    };
+}
+
+#[macro_attr]
+#[derive(Debug, Name!)]
+#[macro_attr(rename_to!(Renamed))]
+pub struct Original(u8);
+
+macro_rules! boxed {
+    (
+        (), then $cb:tt,
+        $(#[$($attrs:tt)*])* $vis:vis $name:ident: $ty:ty, $($tail:tt)*
+    ) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* $vis $name: Box<$ty>, $($tail)* }
+    };
+}
+
+#[macro_attr]
+#[derive(Debug)]
+struct Fields {
+    #[macro_attr(boxed!)]
+    a: u8,
+    #[macro_attr(label = "kept for derivations, but not emitted")]
+    b: u8,
+}
+
+#[macro_attr]
+#[derive(Name!)]
+#[macro_attr_trace(TRACED_TRACE)]
+struct Traced;
+
+macro_rules! Derives {
+    (() { derive($($bi_drvs:tt)*), $($_ctx:tt)* } $_vis:vis struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn derives() -> &'static str { stringify!($($bi_drvs)*) }
+        }
+    };
+}
+
+#[macro_attr]
+#[derive(Clone, Derives@!)]
+struct WithContext;
+
+#[test]
+fn test_macro_attrs() {
+    assert_eq!(Renamed::name(), "Renamed");
+    assert_eq!(format!("{:?}", Renamed(1)), "Renamed(1)");
+}
+
+#[test]
+fn test_field_macro_attrs() {
+    assert_eq!(format!("{:?}", Fields { a: Box::new(1), b: 2 }), "Fields { a: 1, b: 2 }");
+}
+
+#[test]
+fn test_trace() {
+    assert_eq!(Traced::name(), "Traced");
+    assert!(TRACED_TRACE.contains("derivation: Name"));
+}
+
+#[test]
+fn test_drv_context() {
+    assert_eq!(WithContext::derives().replace(" ", ""), "Clone,");
+}
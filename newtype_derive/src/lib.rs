@@ -71,7 +71,46 @@ This crate provides macros to derive implementations of the following traits for
 
 All of these macros are named `Newtype$Trait`.
 
-None of these macros currently support generic newtype structs.
+Generic newtype structs are supported: the derived implementations carry over the struct's generic parameters, bounds and `where` clause.  Any bounds the wrapped type needs for a given trait must be given on the struct itself, as in the example below.
+
+```rust
+#[macro_use] extern crate macro_attr;
+#[macro_use] extern crate newtype_derive;
+
+use std::ops::Add;
+
+macro_attr! {
+    #[derive(Copy, Clone, Debug, PartialEq, NewtypeAdd!, NewtypeDisplay!)]
+    pub struct Total<T>(T) where T: Add<Output=T> + std::fmt::Display;
+}
+
+# fn main() {
+assert_eq!(Total(1) + Total(2), Total(3));
+assert_eq!(format!("{}", Total(1.5) + Total(2.0)), "3.5");
+# }
+```
+
+Using one of these on anything other than a tuple struct with exactly one field, or with arguments it doesn't accept, results in a compile error naming the derivation and the type; *e.g.* ``cannot derive `NewtypeAdd!` for struct `Point`: expected a tuple struct with exactly one field``.
+
+```compile_fail
+# #[macro_use] extern crate macro_attr;
+# #[macro_use] extern crate newtype_derive;
+macro_attr! {
+    #[derive(NewtypeAdd!)]
+    struct Point(i32, i32);
+}
+# fn main() {}
+```
+
+```compile_fail
+# #[macro_use] extern crate macro_attr;
+# #[macro_use] extern crate newtype_derive;
+macro_attr! {
+    #[derive(NewtypeDeref!(mut))]
+    struct Meters(f32);
+}
+# fn main() {}
+```
 
 ## Binary Arithmetic Operators
 
@@ -116,7 +155,7 @@ The deriving macros for the formatting traits in [`std::fmt`][] forward to the w
 
 ## Miscellaneous
 
-`NewtypeFrom` implements `std::convert::From` twice: once for converting from the wrapped type to the newtype, and once for converting from the newtype to the wrapped type.
+`NewtypeFrom` implements `std::convert::From` twice: once for converting from the wrapped type to the newtype, and once for converting from the newtype to the wrapped type.  For generic newtypes, the second implementation is subject to the usual coherence rules, so `NewtypeFrom` can't be used on a newtype that wraps a bare type parameter (*e.g.* `struct Wrapper<T>(T)`).
 
 `NewtypeProduct` and `NewtypeSum` optionally support specifying `&Self` as an argument to generate an implementation that accepts an iterator of borrowed pointers (*e.g.* `NewtypeSum(&Self)`).
 
@@ -146,6 +185,25 @@ NewtypeAdd! { () pub struct Meters(f32); }
 NewtypeAdd! { (f32) pub struct Meters(f32); }
 # fn main() {}
 ```
+
+## Using Without `#[macro_use]`
+
+The macros in this crate don't depend on any other macros having been imported, so they can be imported individually (*e.g.* `#[macro_use(NewtypeFrom, NewtypeAdd)]`).  They can also be named by path, in which case the crate doesn't need to be imported with `#[macro_use]` at all:
+
+```rust
+#[macro_use] extern crate macro_attr;
+extern crate newtype_derive;
+
+macro_attr! {
+    #[derive(Copy, Clone, Debug, newtype_derive::NewtypeFrom!, newtype_derive::NewtypeAdd!)]
+    pub struct Meters(f32);
+}
+
+# fn main() {
+let m = Meters::from(1.5) + Meters::from(2.0);
+assert_eq!(f32::from(m), 3.5);
+# }
+```
 */
 /*
 # `Newtype$binop` Template
@@ -157,22 +215,22 @@ Given `/\/\/\s*(ntbop\s+([A-Za-z0-9]+),\s*([a-z_]+))\n(^#\[.+?\]$\n)*^macro_rule
 #[macro_export]
 macro_rules! Newtype\2 {
     ((*) $($tts:tt)*) => {
-        Newtype\2! { () $($tts)* }
-        Newtype\2! { (&self) $($tts)* }
-        Newtype\2! { (&Self) $($tts)* }
-        Newtype\2! { (&self, Self) $($tts)* }
+        $crate::Newtype\2! { () $($tts)* }
+        $crate::Newtype\2! { (&self) $($tts)* }
+        $crate::Newtype\2! { (&Self) $($tts)* }
+        $crate::Newtype\2! { (&self, Self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::\2)::\3, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::\2)::\3, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::\2)::\3, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::\2)::\3, kind: simple_ref, item: $($tts)* }
     };
     ((&self, $($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::\2)::\3, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::\2)::\3, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::\2)::\3, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::\2)::\3, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
     };
 }
 ```
@@ -187,14 +245,14 @@ Given `/\/\/\s*(ntbopass\s+([A-Za-z0-9]+),\s*([a-z_]+))\n(^#\[.+?\]$\n)*^macro_r
 #[cfg(op_assign)]
 macro_rules! Newtype\2 {
     ((*) $($tts:tt)*) => {
-        Newtype\2! { () $($tts)* }
-        Newtype\2! { (&self) $($tts)* }
+        $crate::Newtype\2! { () $($tts)* }
+        $crate::Newtype\2! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::\2)::\3, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::\2)::\3, kind: simple, item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::\2)::\3, kind: rhs($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::\2)::\3, kind: rhs($($rhs)*), item: $($tts)* }
     };
 }
 ```
@@ -208,20 +266,23 @@ Given `/\/\/\s*(ntuop\s+([A-Za-z0-9]+),\s*([a-z_]+))\n(^#\[.+?\]$\n)*^macro_rule
 #[macro_export]
 macro_rules! Newtype\2 {
     ((*) $($tts:tt)*) => {
-        Newtype\2! { () $($tts)* }
-        Newtype\2! { (&self) $($tts)* }
+        $crate::Newtype\2! { () $($tts)* }
+        $crate::Newtype\2! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_un_op! { trait: (::std::ops::\2)::\3, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_un_op! { trait: (::std::ops::\2)::\3, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_un_op! { trait: (::std::ops::\2)::\3, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_un_op! { trait: (::std::ops::\2)::\3, kind: simple_ref, item: $($tts)* }
     };
 }
 ```
 */
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[doc(hidden)]
+pub extern crate macro_attr as __macro_attr;
+
 mod std_unstable;
 
 #[doc(hidden)]
@@ -232,77 +293,92 @@ macro_rules! newtype_as_item {
 
 #[doc(hidden)]
 #[macro_export]
-macro_rules! newtype_wrap_bin_op {
+macro_rules! newtype_parse {
+    (($($drv:ident)*), $cb:tt, $($item:tt)*) => {
+        $crate::__macro_attr::macro_attr_parse_item! {
+            ($crate::newtype_parse! { @item ($($drv)*), $cb, }),
+            $($item)*
+        }
+    };
+
     (
-        trait: ($($tr:tt)*)::$meth:ident,
-        kind: simple,
-        item: $(pub)* struct $name:ident(pub $t:ty);
+        @item $drvs:tt, $cb:tt,
+        attrs: $_attrs:tt,
+        vis: $_vis:tt,
+        kind: struct,
+        name: $name:ident,
+        generics: $_generics:tt,
+        impl_generics: $impl_gen:tt,
+        ty_generics: $ty_gen:tt,
+        ty_params: $_ty_params:tt,
+        where: $where_:tt,
+        body: (($($fields:tt)*))
     ) => {
-        newtype_as_item! {
-            impl $($tr)*<$name> for $name {
-                type Output = $name;
-                fn $meth(self, rhs: Self) -> $name {
-                    $name((self.0).$meth(rhs.0))
-                }
-            }
+        $crate::__macro_attr::macro_attr_parse_tuple_fields! {
+            ($crate::newtype_parse! { @fields $drvs, $cb, ($name, $impl_gen, $ty_gen, $where_), }),
+            $($fields)*
         }
     };
 
     (
-        trait: ($($tr:tt)*)::$meth:ident,
-        kind: simple,
-        item: $(pub)* struct $name:ident($t:ty);
+        @item ($($drv:ident)*), $_cb:tt,
+        attrs: $_attrs:tt,
+        vis: $_vis:tt,
+        kind: $kind:ident,
+        name: $name:ident,
+        $($_tail:tt)*
     ) => {
-        newtype_as_item! {
-            impl $($tr)*<$name> for $name {
-                type Output = $name;
-                fn $meth(self, rhs: Self) -> $name {
-                    $name((self.0).$meth(rhs.0))
-                }
-            }
-        }
+        $crate::newtype_parse! { @error ($($drv)*), $kind, $name }
     };
 
     (
-        trait: ($($tr:tt)*)::$meth:ident,
-        kind: simple_ref,
-        item: $(pub)* struct $name:ident(pub $t:ty);
+        @fields $_drvs:tt, $cb:tt, ($name:ident, $impl_gen:tt, ($($ty_gen:tt)*), $where_:tt),
+        fields: ((attrs: $_attrs:tt, vis: $_vis:tt, index: $_index:tt, ty: $t:ty),)
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)*<&'a $name> for &'a $name {
-                type Output = $name;
-                fn $meth(self, rhs: Self) -> $name {
-                    $name((self.0).$meth(rhs.0))
-                }
-            }
+        $crate::__macro_attr::macro_attr_callback! {
+            $cb, name: $name, impl_generics: $impl_gen, ty: $name<$($ty_gen)*>, where: $where_, inner: $t
         }
     };
 
     (
-        trait: ($($tr:tt)*)::$meth:ident,
-        kind: simple_ref,
-        item: $(pub)* struct $name:ident($t:ty);
+        @fields $drvs:tt, $_cb:tt, ($name:ident, $($_gen:tt)*),
+        fields: $_fields:tt
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)*<&'a $name> for &'a $name {
-                type Output = $name;
-                fn $meth(self, rhs: Self) -> $name {
-                    $name((self.0).$meth(rhs.0))
-                }
-            }
-        }
+        $crate::newtype_parse! { @error $drvs, struct, $name }
+    };
+
+    (@error ($($drv:ident)*), $kind:ident, $name:ident) => {
+        compile_error!(concat!(
+            "cannot derive `", $(stringify!($drv),)* "!` for ", stringify!($kind), " `", stringify!($name),
+            "`: expected a tuple struct with exactly one field"
+        ));
     };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! newtype_unsupported {
+    (($($drv:ident)*), $arg:tt, $name:ident) => {
+        compile_error!(concat!(
+            "cannot derive `", $(stringify!($drv),)* "!` for `", stringify!($name),
+            "`: unsupported derivation arguments `", stringify!($arg), "`"
+        ));
+    };
+}
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! newtype_wrap_bin_op {
     (
         trait: ($($tr:tt)*)::$meth:ident,
-        kind: rhs_rewrap(&Self),
-        item: $(pub)* struct $name:ident(pub $t:ty);
+        kind: simple,
+        name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t:ty
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)*<&'a $name> for $name {
-                type Output = $name;
-                fn $meth(self, rhs: &'a $name) -> $name {
-                    $name((self.0).$meth(&rhs.0))
+        $crate::newtype_as_item! {
+            impl<$($impl_gen)*> $($tr)*<$ty> for $ty where $($where_)* {
+                type Output = $ty;
+                fn $meth(self, rhs: Self) -> $ty {
+                    $name((self.0).$meth(rhs.0))
                 }
             }
         }
@@ -310,14 +386,14 @@ macro_rules! newtype_wrap_bin_op {
 
     (
         trait: ($($tr:tt)*)::$meth:ident,
-        kind: rhs_rewrap(&Self),
-        item: $(pub)* struct $name:ident($t:ty);
+        kind: simple_ref,
+        name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t:ty
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)*<&'a $name> for $name {
-                type Output = $name;
-                fn $meth(self, rhs: &'a $name) -> $name {
-                    $name((self.0).$meth(&rhs.0))
+        $crate::newtype_as_item! {
+            impl<'a, $($impl_gen)*> $($tr)*<&'a $ty> for &'a $ty where $($where_)* {
+                type Output = $ty;
+                fn $meth(self, rhs: Self) -> $ty {
+                    $name((self.0).$meth(rhs.0))
                 }
             }
         }
@@ -325,14 +401,14 @@ macro_rules! newtype_wrap_bin_op {
 
     (
         trait: ($($tr:tt)*)::$meth:ident,
-        kind: rhs_rewrap($rhs:ty),
-        item: $(pub)* struct $name:ident(pub $t:ty);
+        kind: rhs_rewrap(&Self),
+        name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t:ty
     ) => {
-        newtype_as_item! {
-            impl $($tr)*<$rhs> for $name {
-                type Output = $name;
-                fn $meth(self, rhs: $rhs) -> $name {
-                    $name((self.0).$meth(rhs))
+        $crate::newtype_as_item! {
+            impl<'a, $($impl_gen)*> $($tr)*<&'a $ty> for $ty where $($where_)* {
+                type Output = $ty;
+                fn $meth(self, rhs: &'a $ty) -> $ty {
+                    $name((self.0).$meth(&rhs.0))
                 }
             }
         }
@@ -341,12 +417,12 @@ macro_rules! newtype_wrap_bin_op {
     (
         trait: ($($tr:tt)*)::$meth:ident,
         kind: rhs_rewrap($rhs:ty),
-        item: $(pub)* struct $name:ident($t:ty);
+        name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t:ty
     ) => {
-        newtype_as_item! {
-            impl $($tr)*<$rhs> for $name {
-                type Output = $name;
-                fn $meth(self, rhs: $rhs) -> $name {
+        $crate::newtype_as_item! {
+            impl<$($impl_gen)*> $($tr)*<$rhs> for $ty where $($where_)* {
+                type Output = $ty;
+                fn $meth(self, rhs: $rhs) -> $ty {
                     $name((self.0).$meth(rhs))
                 }
             }
@@ -356,12 +432,12 @@ macro_rules! newtype_wrap_bin_op {
     (
         trait: ($($tr:tt)*)::$meth:ident,
         kind: ref_rhs_rewrap(Self),
-        item: $(pub)* struct $name:ident(pub $t:ty);
+        name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t:ty
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)*<$name> for &'a $name {
-                type Output = $name;
-                fn $meth(self, rhs: $name) -> $name {
+        $crate::newtype_as_item! {
+            impl<'a, $($impl_gen)*> $($tr)*<$ty> for &'a $ty where $($where_)* {
+                type Output = $ty;
+                fn $meth(self, rhs: $ty) -> $ty {
                     $name((self.0).$meth(rhs.0))
                 }
             }
@@ -370,46 +446,36 @@ macro_rules! newtype_wrap_bin_op {
 
     (
         trait: ($($tr:tt)*)::$meth:ident,
-        kind: ref_rhs_rewrap(Self),
-        item: $(pub)* struct $name:ident($t:ty);
+        kind: ref_rhs_rewrap($rhs:ty),
+        name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t:ty
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)*<$name> for &'a $name {
-                type Output = $name;
-                fn $meth(self, rhs: $name) -> $name {
-                    $name((self.0).$meth(rhs.0))
+        $crate::newtype_as_item! {
+            impl<'a, $($impl_gen)*> $($tr)*<$rhs> for &'a $ty where $($where_)* {
+                type Output = $ty;
+                fn $meth(self, rhs: $rhs) -> $ty {
+                    $name((self.0).$meth(rhs))
                 }
             }
         }
     };
 
     (
-        trait: ($($tr:tt)*)::$meth:ident,
-        kind: ref_rhs_rewrap($rhs:ty),
-        item: $(pub)* struct $name:ident(pub $t:ty);
+        trait: (::std::ops::$tr:ident)::$_meth:ident,
+        kind: $kind:ident $(($($kind_args:tt)*))*,
+        name: $name:ident, $($_tail:tt)*
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)*<$rhs> for &'a $name {
-                type Output = $name;
-                fn $meth(self, rhs: $rhs) -> $name {
-                    $name((self.0).$meth(rhs))
-                }
-            }
-        }
+        $crate::newtype_unsupported! { (Newtype $tr), ($kind $(($($kind_args)*))*), $name }
     };
 
     (
-        trait: ($($tr:tt)*)::$meth:ident,
-        kind: ref_rhs_rewrap($rhs:ty),
-        item: $(pub)* struct $name:ident($t:ty);
+        trait: (::std::ops::$tr:ident)::$meth:ident,
+        kind: $kind:ident $(($($kind_args:tt)*))*,
+        item: $($item:tt)*
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)*<$rhs> for &'a $name {
-                type Output = $name;
-                fn $meth(self, rhs: $rhs) -> $name {
-                    $name((self.0).$meth(rhs))
-                }
-            }
+        $crate::newtype_parse! {
+            (Newtype $tr),
+            ($crate::newtype_wrap_bin_op! { trait: (::std::ops::$tr)::$meth, kind: $kind $(($($kind_args)*))*, }),
+            $($item)*
         }
     };
 }
@@ -420,24 +486,10 @@ macro_rules! newtype_wrap_bin_op_assign {
     (
         trait: ($($tr:tt)*)::$meth:ident,
         kind: simple,
-        item: $(pub)* struct $name:ident(pub $t:ty);
+        name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t:ty
     ) => {
-        newtype_as_item! {
-            impl $($tr)*<$name> for $name {
-                fn $meth(&mut self, rhs: Self) {
-                    (self.0).$meth(rhs.0)
-                }
-            }
-        }
-    };
-
-    (
-        trait: ($($tr:tt)*)::$meth:ident,
-        kind: simple,
-        item: $(pub)* struct $name:ident($t:ty);
-    ) => {
-        newtype_as_item! {
-            impl $($tr)*<$name> for $name {
+        $crate::newtype_as_item! {
+            impl<$($impl_gen)*> $($tr)*<$ty> for $ty where $($where_)* {
                 fn $meth(&mut self, rhs: Self) {
                     (self.0).$meth(rhs.0)
                 }
@@ -448,11 +500,11 @@ macro_rules! newtype_wrap_bin_op_assign {
     (
         trait: ($($tr:tt)*)::$meth:ident,
         kind: rhs(&Self),
-        item: $(pub)* struct $name:ident(pub $t:ty);
+        name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t:ty
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)*<&'a $name> for $name {
-                fn $meth(&mut self, rhs: &'a $name) {
+        $crate::newtype_as_item! {
+            impl<'a, $($impl_gen)*> $($tr)*<&'a $ty> for $ty where $($where_)* {
+                fn $meth(&mut self, rhs: &'a $ty) {
                     (self.0).$meth(rhs.0)
                 }
             }
@@ -461,43 +513,35 @@ macro_rules! newtype_wrap_bin_op_assign {
 
     (
         trait: ($($tr:tt)*)::$meth:ident,
-        kind: rhs(&Self),
-        item: $(pub)* struct $name:ident($t:ty);
+        kind: rhs($rhs:ty),
+        name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t:ty
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)*<&'a $name> for $name {
-                fn $meth(&mut self, rhs: &'a $name) {
-                    (self.0).$meth(rhs.0)
+        $crate::newtype_as_item! {
+            impl<$($impl_gen)*> $($tr)*<$rhs> for $ty where $($where_)* {
+                fn $meth(&mut self, rhs: $rhs) {
+                    (self.0).$meth(rhs)
                 }
             }
         }
     };
 
     (
-        trait: ($($tr:tt)*)::$meth:ident,
-        kind: rhs($rhs:ty),
-        item: $(pub)* struct $name:ident(pub $t:ty);
+        trait: (::std::ops::$tr:ident)::$_meth:ident,
+        kind: $kind:ident $(($($kind_args:tt)*))*,
+        name: $name:ident, $($_tail:tt)*
     ) => {
-        newtype_as_item! {
-            impl $($tr)*<$rhs> for $name {
-                fn $meth(&mut self, rhs: $rhs) {
-                    (self.0).$meth(rhs)
-                }
-            }
-        }
+        $crate::newtype_unsupported! { (Newtype $tr), ($kind $(($($kind_args)*))*), $name }
     };
 
     (
-        trait: ($($tr:tt)*)::$meth:ident,
-        kind: rhs($rhs:ty),
-        item: $(pub)* struct $name:ident($t:ty);
+        trait: (::std::ops::$tr:ident)::$meth:ident,
+        kind: $kind:ident $(($($kind_args:tt)*))*,
+        item: $($item:tt)*
     ) => {
-        newtype_as_item! {
-            impl $($tr)*<$rhs> for $name {
-                fn $meth(&mut self, rhs: $rhs) {
-                    (self.0).$meth(rhs)
-                }
-            }
+        $crate::newtype_parse! {
+            (Newtype $tr),
+            ($crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::$tr)::$meth, kind: $kind $(($($kind_args)*))*, }),
+            $($item)*
         }
     };
 }
@@ -508,12 +552,12 @@ macro_rules! newtype_wrap_un_op {
     (
         trait: ($($tr:tt)*)::$meth:ident,
         kind: simple,
-        item: $(pub)* struct $name:ident(pub $t:ty);
+        name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t:ty
     ) => {
-        newtype_as_item! {
-            impl $($tr)* for $name {
-                type Output = $name;
-                fn $meth(self) -> $name {
+        $crate::newtype_as_item! {
+            impl<$($impl_gen)*> $($tr)* for $ty where $($where_)* {
+                type Output = $ty;
+                fn $meth(self) -> $ty {
                     $name((self.0).$meth())
                 }
             }
@@ -522,13 +566,13 @@ macro_rules! newtype_wrap_un_op {
 
     (
         trait: ($($tr:tt)*)::$meth:ident,
-        kind: simple,
-        item: $(pub)* struct $name:ident($t:ty);
+        kind: simple_ref,
+        name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t:ty
     ) => {
-        newtype_as_item! {
-            impl $($tr)* for $name {
-                type Output = $name;
-                fn $meth(self) -> $name {
+        $crate::newtype_as_item! {
+            impl<'a, $($impl_gen)*> $($tr)* for &'a $ty where $($where_)* {
+                type Output = $ty;
+                fn $meth(self) -> $ty {
                     $name((self.0).$meth())
                 }
             }
@@ -536,32 +580,22 @@ macro_rules! newtype_wrap_un_op {
     };
 
     (
-        trait: ($($tr:tt)*)::$meth:ident,
-        kind: simple_ref,
-        item: $(pub)* struct $name:ident(pub $t:ty);
+        trait: (::std::ops::$tr:ident)::$_meth:ident,
+        kind: $kind:ident $(($($kind_args:tt)*))*,
+        name: $name:ident, $($_tail:tt)*
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)* for &'a $name {
-                type Output = $name;
-                fn $meth(self) -> $name {
-                    $name((self.0).$meth())
-                }
-            }
-        }
+        $crate::newtype_unsupported! { (Newtype $tr), ($kind $(($($kind_args)*))*), $name }
     };
 
     (
-        trait: ($($tr:tt)*)::$meth:ident,
-        kind: simple_ref,
-        item: $(pub)* struct $name:ident($t:ty);
+        trait: (::std::ops::$tr:ident)::$meth:ident,
+        kind: $kind:ident $(($($kind_args:tt)*))*,
+        item: $($item:tt)*
     ) => {
-        newtype_as_item! {
-            impl<'a> $($tr)* for &'a $name {
-                type Output = $name;
-                fn $meth(self) -> $name {
-                    $name((self.0).$meth())
-                }
-            }
+        $crate::newtype_parse! {
+            (Newtype $tr),
+            ($crate::newtype_wrap_un_op! { trait: (::std::ops::$tr)::$meth, kind: $kind $(($($kind_args)*))*, }),
+            $($item)*
         }
     };
 }
@@ -570,22 +604,22 @@ macro_rules! newtype_wrap_un_op {
 #[macro_export]
 macro_rules! NewtypeAdd {
     ((*) $($tts:tt)*) => {
-        NewtypeAdd! { () $($tts)* }
-        NewtypeAdd! { (&self) $($tts)* }
-        NewtypeAdd! { (&Self) $($tts)* }
-        NewtypeAdd! { (&self, Self) $($tts)* }
+        $crate::NewtypeAdd! { () $($tts)* }
+        $crate::NewtypeAdd! { (&self) $($tts)* }
+        $crate::NewtypeAdd! { (&Self) $($tts)* }
+        $crate::NewtypeAdd! { (&self, Self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Add)::add, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Add)::add, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Add)::add, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Add)::add, kind: simple_ref, item: $($tts)* }
     };
     ((&self, $($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Add)::add, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Add)::add, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Add)::add, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Add)::add, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -594,14 +628,14 @@ macro_rules! NewtypeAdd {
 #[cfg(op_assign)]
 macro_rules! NewtypeAddAssign {
     ((*) $($tts:tt)*) => {
-        NewtypeAddAssign! { () $($tts)* }
-        NewtypeAddAssign! { (&self) $($tts)* }
+        $crate::NewtypeAddAssign! { () $($tts)* }
+        $crate::NewtypeAddAssign! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::AddAssign)::add_assign, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::AddAssign)::add_assign, kind: simple, item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::AddAssign)::add_assign, kind: rhs($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::AddAssign)::add_assign, kind: rhs($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -609,22 +643,22 @@ macro_rules! NewtypeAddAssign {
 #[macro_export]
 macro_rules! NewtypeBitAnd {
     ((*) $($tts:tt)*) => {
-        NewtypeBitAnd! { () $($tts)* }
-        NewtypeBitAnd! { (&self) $($tts)* }
-        NewtypeBitAnd! { (&Self) $($tts)* }
-        NewtypeBitAnd! { (&self, Self) $($tts)* }
+        $crate::NewtypeBitAnd! { () $($tts)* }
+        $crate::NewtypeBitAnd! { (&self) $($tts)* }
+        $crate::NewtypeBitAnd! { (&Self) $($tts)* }
+        $crate::NewtypeBitAnd! { (&self, Self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitAnd)::bitand, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitAnd)::bitand, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitAnd)::bitand, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitAnd)::bitand, kind: simple_ref, item: $($tts)* }
     };
     ((&self, $($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitAnd)::bitand, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitAnd)::bitand, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitAnd)::bitand, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitAnd)::bitand, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -633,14 +667,14 @@ macro_rules! NewtypeBitAnd {
 #[cfg(op_assign)]
 macro_rules! NewtypeBitAndAssign {
     ((*) $($tts:tt)*) => {
-        NewtypeBitAndAssign! { () $($tts)* }
-        NewtypeBitAndAssign! { (&self) $($tts)* }
+        $crate::NewtypeBitAndAssign! { () $($tts)* }
+        $crate::NewtypeBitAndAssign! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::BitAndAssign)::bitand_assign, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::BitAndAssign)::bitand_assign, kind: simple, item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::BitAndAssign)::bitand_assign, kind: rhs($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::BitAndAssign)::bitand_assign, kind: rhs($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -648,22 +682,22 @@ macro_rules! NewtypeBitAndAssign {
 #[macro_export]
 macro_rules! NewtypeBitOr {
     ((*) $($tts:tt)*) => {
-        NewtypeBitOr! { () $($tts)* }
-        NewtypeBitOr! { (&self) $($tts)* }
-        NewtypeBitOr! { (&Self) $($tts)* }
-        NewtypeBitOr! { (&self, Self) $($tts)* }
+        $crate::NewtypeBitOr! { () $($tts)* }
+        $crate::NewtypeBitOr! { (&self) $($tts)* }
+        $crate::NewtypeBitOr! { (&Self) $($tts)* }
+        $crate::NewtypeBitOr! { (&self, Self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitOr)::bitor, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitOr)::bitor, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitOr)::bitor, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitOr)::bitor, kind: simple_ref, item: $($tts)* }
     };
     ((&self, $($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitOr)::bitor, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitOr)::bitor, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitOr)::bitor, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitOr)::bitor, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -672,14 +706,14 @@ macro_rules! NewtypeBitOr {
 #[cfg(op_assign)]
 macro_rules! NewtypeBitOrAssign {
     ((*) $($tts:tt)*) => {
-        NewtypeBitOrAssign! { () $($tts)* }
-        NewtypeBitOrAssign! { (&self) $($tts)* }
+        $crate::NewtypeBitOrAssign! { () $($tts)* }
+        $crate::NewtypeBitOrAssign! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::BitOrAssign)::bitor_assign, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::BitOrAssign)::bitor_assign, kind: simple, item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::BitOrAssign)::bitor_assign, kind: rhs($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::BitOrAssign)::bitor_assign, kind: rhs($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -687,22 +721,22 @@ macro_rules! NewtypeBitOrAssign {
 #[macro_export]
 macro_rules! NewtypeBitXor {
     ((*) $($tts:tt)*) => {
-        NewtypeBitXor! { () $($tts)* }
-        NewtypeBitXor! { (&self) $($tts)* }
-        NewtypeBitXor! { (&Self) $($tts)* }
-        NewtypeBitXor! { (&self, Self) $($tts)* }
+        $crate::NewtypeBitXor! { () $($tts)* }
+        $crate::NewtypeBitXor! { (&self) $($tts)* }
+        $crate::NewtypeBitXor! { (&Self) $($tts)* }
+        $crate::NewtypeBitXor! { (&self, Self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitXor)::bitxor, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitXor)::bitxor, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitXor)::bitxor, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitXor)::bitxor, kind: simple_ref, item: $($tts)* }
     };
     ((&self, $($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitXor)::bitxor, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitXor)::bitxor, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::BitXor)::bitxor, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::BitXor)::bitxor, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -711,14 +745,14 @@ macro_rules! NewtypeBitXor {
 #[cfg(op_assign)]
 macro_rules! NewtypeBitXorAssign {
     ((*) $($tts:tt)*) => {
-        NewtypeBitXorAssign! { () $($tts)* }
-        NewtypeBitXorAssign! { (&self) $($tts)* }
+        $crate::NewtypeBitXorAssign! { () $($tts)* }
+        $crate::NewtypeBitXorAssign! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::BitXorAssign)::bitxor_assign, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::BitXorAssign)::bitxor_assign, kind: simple, item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::BitXorAssign)::bitxor_assign, kind: rhs($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::BitXorAssign)::bitxor_assign, kind: rhs($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -726,22 +760,22 @@ macro_rules! NewtypeBitXorAssign {
 #[macro_export]
 macro_rules! NewtypeDiv {
     ((*) $($tts:tt)*) => {
-        NewtypeDiv! { () $($tts)* }
-        NewtypeDiv! { (&self) $($tts)* }
-        NewtypeDiv! { (&Self) $($tts)* }
-        NewtypeDiv! { (&self, Self) $($tts)* }
+        $crate::NewtypeDiv! { () $($tts)* }
+        $crate::NewtypeDiv! { (&self) $($tts)* }
+        $crate::NewtypeDiv! { (&Self) $($tts)* }
+        $crate::NewtypeDiv! { (&self, Self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Div)::div, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Div)::div, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Div)::div, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Div)::div, kind: simple_ref, item: $($tts)* }
     };
     ((&self, $($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Div)::div, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Div)::div, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Div)::div, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Div)::div, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -750,14 +784,14 @@ macro_rules! NewtypeDiv {
 #[cfg(op_assign)]
 macro_rules! NewtypeDivAssign {
     ((*) $($tts:tt)*) => {
-        NewtypeDivAssign! { () $($tts)* }
-        NewtypeDivAssign! { (&self) $($tts)* }
+        $crate::NewtypeDivAssign! { () $($tts)* }
+        $crate::NewtypeDivAssign! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::DivAssign)::div_assign, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::DivAssign)::div_assign, kind: simple, item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::DivAssign)::div_assign, kind: rhs($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::DivAssign)::div_assign, kind: rhs($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -765,22 +799,22 @@ macro_rules! NewtypeDivAssign {
 #[macro_export]
 macro_rules! NewtypeMul {
     ((*) $($tts:tt)*) => {
-        NewtypeMul! { () $($tts)* }
-        NewtypeMul! { (&self) $($tts)* }
-        NewtypeMul! { (&Self) $($tts)* }
-        NewtypeMul! { (&self, Self) $($tts)* }
+        $crate::NewtypeMul! { () $($tts)* }
+        $crate::NewtypeMul! { (&self) $($tts)* }
+        $crate::NewtypeMul! { (&Self) $($tts)* }
+        $crate::NewtypeMul! { (&self, Self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Mul)::mul, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Mul)::mul, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Mul)::mul, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Mul)::mul, kind: simple_ref, item: $($tts)* }
     };
     ((&self, $($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Mul)::mul, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Mul)::mul, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Mul)::mul, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Mul)::mul, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -789,14 +823,14 @@ macro_rules! NewtypeMul {
 #[cfg(op_assign)]
 macro_rules! NewtypeMulAssign {
     ((*) $($tts:tt)*) => {
-        NewtypeMulAssign! { () $($tts)* }
-        NewtypeMulAssign! { (&self) $($tts)* }
+        $crate::NewtypeMulAssign! { () $($tts)* }
+        $crate::NewtypeMulAssign! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::MulAssign)::mul_assign, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::MulAssign)::mul_assign, kind: simple, item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::MulAssign)::mul_assign, kind: rhs($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::MulAssign)::mul_assign, kind: rhs($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -804,22 +838,22 @@ macro_rules! NewtypeMulAssign {
 #[macro_export]
 macro_rules! NewtypeRem {
     ((*) $($tts:tt)*) => {
-        NewtypeRem! { () $($tts)* }
-        NewtypeRem! { (&self) $($tts)* }
-        NewtypeRem! { (&Self) $($tts)* }
-        NewtypeRem! { (&self, Self) $($tts)* }
+        $crate::NewtypeRem! { () $($tts)* }
+        $crate::NewtypeRem! { (&self) $($tts)* }
+        $crate::NewtypeRem! { (&Self) $($tts)* }
+        $crate::NewtypeRem! { (&self, Self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Rem)::rem, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Rem)::rem, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Rem)::rem, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Rem)::rem, kind: simple_ref, item: $($tts)* }
     };
     ((&self, $($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Rem)::rem, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Rem)::rem, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Rem)::rem, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Rem)::rem, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -828,14 +862,14 @@ macro_rules! NewtypeRem {
 #[cfg(op_assign)]
 macro_rules! NewtypeRemAssign {
     ((*) $($tts:tt)*) => {
-        NewtypeRemAssign! { () $($tts)* }
-        NewtypeRemAssign! { (&self) $($tts)* }
+        $crate::NewtypeRemAssign! { () $($tts)* }
+        $crate::NewtypeRemAssign! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::RemAssign)::rem_assign, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::RemAssign)::rem_assign, kind: simple, item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::RemAssign)::rem_assign, kind: rhs($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::RemAssign)::rem_assign, kind: rhs($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -843,22 +877,22 @@ macro_rules! NewtypeRemAssign {
 #[macro_export]
 macro_rules! NewtypeSub {
     ((*) $($tts:tt)*) => {
-        NewtypeSub! { () $($tts)* }
-        NewtypeSub! { (&self) $($tts)* }
-        NewtypeSub! { (&Self) $($tts)* }
-        NewtypeSub! { (&self, Self) $($tts)* }
+        $crate::NewtypeSub! { () $($tts)* }
+        $crate::NewtypeSub! { (&self) $($tts)* }
+        $crate::NewtypeSub! { (&Self) $($tts)* }
+        $crate::NewtypeSub! { (&self, Self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Sub)::sub, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Sub)::sub, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Sub)::sub, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Sub)::sub, kind: simple_ref, item: $($tts)* }
     };
     ((&self, $($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Sub)::sub, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Sub)::sub, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Sub)::sub, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Sub)::sub, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -867,14 +901,14 @@ macro_rules! NewtypeSub {
 #[cfg(op_assign)]
 macro_rules! NewtypeSubAssign {
     ((*) $($tts:tt)*) => {
-        NewtypeSubAssign! { () $($tts)* }
-        NewtypeSubAssign! { (&self) $($tts)* }
+        $crate::NewtypeSubAssign! { () $($tts)* }
+        $crate::NewtypeSubAssign! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::SubAssign)::sub_assign, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::SubAssign)::sub_assign, kind: simple, item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::SubAssign)::sub_assign, kind: rhs($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::SubAssign)::sub_assign, kind: rhs($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -882,22 +916,22 @@ macro_rules! NewtypeSubAssign {
 #[macro_export]
 macro_rules! NewtypeShl {
     ((*) $($tts:tt)*) => {
-        NewtypeShl! { () $($tts)* }
-        NewtypeShl! { (&self) $($tts)* }
-        NewtypeShl! { (&Self) $($tts)* }
-        NewtypeShl! { (&self, Self) $($tts)* }
+        $crate::NewtypeShl! { () $($tts)* }
+        $crate::NewtypeShl! { (&self) $($tts)* }
+        $crate::NewtypeShl! { (&Self) $($tts)* }
+        $crate::NewtypeShl! { (&self, Self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Shl)::shl, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Shl)::shl, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Shl)::shl, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Shl)::shl, kind: simple_ref, item: $($tts)* }
     };
     ((&self, $($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Shl)::shl, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Shl)::shl, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Shl)::shl, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Shl)::shl, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -906,14 +940,14 @@ macro_rules! NewtypeShl {
 #[cfg(op_assign)]
 macro_rules! NewtypeShlAssign {
     ((*) $($tts:tt)*) => {
-        NewtypeShlAssign! { () $($tts)* }
-        NewtypeShlAssign! { (&self) $($tts)* }
+        $crate::NewtypeShlAssign! { () $($tts)* }
+        $crate::NewtypeShlAssign! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::ShlAssign)::shl_assign, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::ShlAssign)::shl_assign, kind: simple, item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::ShlAssign)::shl_assign, kind: rhs($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::ShlAssign)::shl_assign, kind: rhs($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -921,22 +955,22 @@ macro_rules! NewtypeShlAssign {
 #[macro_export]
 macro_rules! NewtypeShr {
     ((*) $($tts:tt)*) => {
-        NewtypeShr! { () $($tts)* }
-        NewtypeShr! { (&self) $($tts)* }
-        NewtypeShr! { (&Self) $($tts)* }
-        NewtypeShr! { (&self, Self) $($tts)* }
+        $crate::NewtypeShr! { () $($tts)* }
+        $crate::NewtypeShr! { (&self) $($tts)* }
+        $crate::NewtypeShr! { (&Self) $($tts)* }
+        $crate::NewtypeShr! { (&self, Self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Shr)::shr, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Shr)::shr, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Shr)::shr, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Shr)::shr, kind: simple_ref, item: $($tts)* }
     };
     ((&self, $($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Shr)::shr, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Shr)::shr, kind: ref_rhs_rewrap($($rhs)*), item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op! { trait: (::std::ops::Shr)::shr, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op! { trait: (::std::ops::Shr)::shr, kind: rhs_rewrap($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -945,14 +979,14 @@ macro_rules! NewtypeShr {
 #[cfg(op_assign)]
 macro_rules! NewtypeShrAssign {
     ((*) $($tts:tt)*) => {
-        NewtypeShrAssign! { () $($tts)* }
-        NewtypeShrAssign! { (&self) $($tts)* }
+        $crate::NewtypeShrAssign! { () $($tts)* }
+        $crate::NewtypeShrAssign! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::ShrAssign)::shr_assign, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::ShrAssign)::shr_assign, kind: simple, item: $($tts)* }
     };
     (($($rhs:tt)*) $($tts:tt)*) => {
-        newtype_wrap_bin_op_assign! { trait: (::std::ops::ShrAssign)::shr_assign, kind: rhs($($rhs)*), item: $($tts)* }
+        $crate::newtype_wrap_bin_op_assign! { trait: (::std::ops::ShrAssign)::shr_assign, kind: rhs($($rhs)*), item: $($tts)* }
     };
 }
 
@@ -960,14 +994,14 @@ macro_rules! NewtypeShrAssign {
 #[macro_export]
 macro_rules! NewtypeNeg {
     ((*) $($tts:tt)*) => {
-        NewtypeNeg! { () $($tts)* }
-        NewtypeNeg! { (&self) $($tts)* }
+        $crate::NewtypeNeg! { () $($tts)* }
+        $crate::NewtypeNeg! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_un_op! { trait: (::std::ops::Neg)::neg, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_un_op! { trait: (::std::ops::Neg)::neg, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_un_op! { trait: (::std::ops::Neg)::neg, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_un_op! { trait: (::std::ops::Neg)::neg, kind: simple_ref, item: $($tts)* }
     };
 }
 
@@ -975,21 +1009,21 @@ macro_rules! NewtypeNeg {
 #[macro_export]
 macro_rules! NewtypeNot {
     ((*) $($tts:tt)*) => {
-        NewtypeNot! { () $($tts)* }
-        NewtypeNot! { (&self) $($tts)* }
+        $crate::NewtypeNot! { () $($tts)* }
+        $crate::NewtypeNot! { (&self) $($tts)* }
     };
     (() $($tts:tt)*) => {
-        newtype_wrap_un_op! { trait: (::std::ops::Not)::not, kind: simple, item: $($tts)* }
+        $crate::newtype_wrap_un_op! { trait: (::std::ops::Not)::not, kind: simple, item: $($tts)* }
     };
     ((&self) $($tts:tt)*) => {
-        newtype_wrap_un_op! { trait: (::std::ops::Not)::not, kind: simple_ref, item: $($tts)* }
+        $crate::newtype_wrap_un_op! { trait: (::std::ops::Not)::not, kind: simple_ref, item: $($tts)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeDeref {
-    (() $(pub)* struct $name:ident(pub $t0:ty);) => {
-        impl ::std::ops::Deref for $name {
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t0:ty) => {
+        impl<$($impl_gen)*> ::std::ops::Deref for $ty where $($where_)* {
             type Target = $t0;
             fn deref(&self) -> &Self::Target {
                 &self.0
@@ -997,39 +1031,38 @@ macro_rules! NewtypeDeref {
         }
     };
 
-    (() $(pub)* struct $name:ident($t0:ty);) => {
-        impl ::std::ops::Deref for $name {
-            type Target = $t0;
-            fn deref(&self) -> &Self::Target {
-                &self.0
-            }
-        }
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeDeref), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeDeref), ($crate::NewtypeDeref! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeDerefMut {
-    (() $(pub)* struct $name:ident(pub $t0:ty);) => {
-        impl ::std::ops::DerefMut for $name {
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t0:ty) => {
+        impl<$($impl_gen)*> ::std::ops::DerefMut for $ty where $($where_)* {
             fn deref_mut(&mut self) -> &mut Self::Target {
                 &mut self.0
             }
         }
     };
 
-    (() $(pub)* struct $name:ident($t0:ty);) => {
-        impl ::std::ops::DerefMut for $name {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.0
-            }
-        }
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeDerefMut), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeDerefMut), ($crate::NewtypeDerefMut! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeIndex {
-    (($index_ty:ty) $(pub)* struct $name:ident(pub $t0:ty);) => {
-        impl ::std::ops::Index<$index_ty> for $name {
+    (@parsed ($index_ty:ty) name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t0:ty) => {
+        impl<$($impl_gen)*> ::std::ops::Index<$index_ty> for $ty where $($where_)* {
             type Output = <$t0 as ::std::ops::Index<$index_ty>>::Output;
             fn index(&self, index: $index_ty) -> &Self::Output {
                 (&self.0).index(index)
@@ -1037,69 +1070,63 @@ macro_rules! NewtypeIndex {
         }
     };
 
-    (($index_ty:ty) $(pub)* struct $name:ident($t0:ty);) => {
-        impl ::std::ops::Index<$index_ty> for $name {
-            type Output = <$t0 as ::std::ops::Index<$index_ty>>::Output;
-            fn index(&self, index: $index_ty) -> &Self::Output {
-                (&self.0).index(index)
-            }
-        }
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeIndex), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeIndex), ($crate::NewtypeIndex! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeIndexMut {
-    (($index_ty:ty) $(pub)* struct $name:ident(pub $t0:ty);) => {
-        impl ::std::ops::IndexMut<$index_ty> for $name {
+    (@parsed ($index_ty:ty) name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t0:ty) => {
+        impl<$($impl_gen)*> ::std::ops::IndexMut<$index_ty> for $ty where $($where_)* {
             fn index_mut(&mut self, index: $index_ty) -> &mut Self::Output {
                 (&mut self.0).index_mut(index)
             }
         }
     };
 
-    (($index_ty:ty) $(pub)* struct $name:ident($t0:ty);) => {
-        impl ::std::ops::IndexMut<$index_ty> for $name {
-            fn index_mut(&mut self, index: $index_ty) -> &mut Self::Output {
-                (&mut self.0).index_mut(index)
-            }
-        }
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeIndexMut), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeIndexMut), ($crate::NewtypeIndexMut! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeFrom {
-    (() $(pub)* struct $name:ident(pub $t0:ty);) => {
-        impl ::std::convert::From<$t0> for $name {
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t0:ty) => {
+        impl<$($impl_gen)*> ::std::convert::From<$t0> for $ty where $($where_)* {
             fn from(v: $t0) -> Self {
                 $name(v)
             }
         }
-        impl ::std::convert::From<$name> for $t0 {
-            fn from(v: $name) -> Self {
+        impl<$($impl_gen)*> ::std::convert::From<$ty> for $t0 where $($where_)* {
+            fn from(v: $ty) -> Self {
                 v.0
             }
         }
     };
 
-    (() $(pub)* struct $name:ident($t0:ty);) => {
-        impl ::std::convert::From<$t0> for $name {
-            fn from(v: $t0) -> Self {
-                $name(v)
-            }
-        }
-        impl ::std::convert::From<$name> for $t0 {
-            fn from(v: $name) -> Self {
-                v.0
-            }
-        }
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeFrom), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeFrom), ($crate::NewtypeFrom! { @parsed $arg }), $($item)* }
     };
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! newtype_fmt {
-    ($fmt_trait:ident, $name:ident) => {
-        impl ::std::fmt::$fmt_trait for $name {
+    ($fmt_trait:ident, ($($impl_gen:tt)*), $ty:ty, ($($where_:tt)*)) => {
+        impl<$($impl_gen)*> ::std::fmt::$fmt_trait for $ty where $($where_)* {
             fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
                 ::std::fmt::$fmt_trait::fmt(&self.0, fmt)
             }
@@ -1109,63 +1136,135 @@ macro_rules! newtype_fmt {
 
 #[macro_export]
 macro_rules! NewtypeBinary {
-    (() $(pub)* struct $name:ident $_field:tt;) => {
-        newtype_fmt! { Binary, $name }
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $_t0:ty) => {
+        $crate::newtype_fmt! { Binary, ($($impl_gen)*), $ty, ($($where_)*) }
+    };
+
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeBinary), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeBinary), ($crate::NewtypeBinary! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeDebug {
-    (() $(pub)* struct $name:ident $_field:tt;) => {
-        newtype_fmt! { Debug, $name }
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $_t0:ty) => {
+        $crate::newtype_fmt! { Debug, ($($impl_gen)*), $ty, ($($where_)*) }
+    };
+
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeDebug), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeDebug), ($crate::NewtypeDebug! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeDisplay {
-    (() $(pub)* struct $name:ident $_field:tt;) => {
-        newtype_fmt! { Display, $name }
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $_t0:ty) => {
+        $crate::newtype_fmt! { Display, ($($impl_gen)*), $ty, ($($where_)*) }
+    };
+
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeDisplay), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeDisplay), ($crate::NewtypeDisplay! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeLowerExp {
-    (() $(pub)* struct $name:ident $_field:tt;) => {
-        newtype_fmt! { LowerExp, $name }
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $_t0:ty) => {
+        $crate::newtype_fmt! { LowerExp, ($($impl_gen)*), $ty, ($($where_)*) }
+    };
+
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeLowerExp), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeLowerExp), ($crate::NewtypeLowerExp! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeLowerHex {
-    (() $(pub)* struct $name:ident $_field:tt;) => {
-        newtype_fmt! { LowerHex, $name }
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $_t0:ty) => {
+        $crate::newtype_fmt! { LowerHex, ($($impl_gen)*), $ty, ($($where_)*) }
+    };
+
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeLowerHex), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeLowerHex), ($crate::NewtypeLowerHex! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeOctal {
-    (() $(pub)* struct $name:ident $_field:tt;) => {
-        newtype_fmt! { Octal, $name }
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $_t0:ty) => {
+        $crate::newtype_fmt! { Octal, ($($impl_gen)*), $ty, ($($where_)*) }
+    };
+
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeOctal), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeOctal), ($crate::NewtypeOctal! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypePointer {
-    (() $(pub)* struct $name:ident $_field:tt;) => {
-        newtype_fmt! { Pointer, $name }
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $_t0:ty) => {
+        $crate::newtype_fmt! { Pointer, ($($impl_gen)*), $ty, ($($where_)*) }
+    };
+
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypePointer), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypePointer), ($crate::NewtypePointer! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeUpperExp {
-    (() $(pub)* struct $name:ident $_field:tt;) => {
-        newtype_fmt! { UpperExp, $name }
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $_t0:ty) => {
+        $crate::newtype_fmt! { UpperExp, ($($impl_gen)*), $ty, ($($where_)*) }
+    };
+
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeUpperExp), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeUpperExp), ($crate::NewtypeUpperExp! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeUpperHex {
-    (() $(pub)* struct $name:ident $_field:tt;) => {
-        newtype_fmt! { UpperHex, $name }
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $_t0:ty) => {
+        $crate::newtype_fmt! { UpperHex, ($($impl_gen)*), $ty, ($($where_)*) }
+    };
+
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeUpperHex), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeUpperHex), ($crate::NewtypeUpperHex! { @parsed $arg }), $($item)* }
     };
 }
@@ -11,88 +11,96 @@ or distributed except according to those terms.
 
 #[macro_export]
 macro_rules! NewtypeOne {
-    (() $(pub)* struct $name:ident(pub $_t0:ty);) => {
-        impl ::std::num::One for $name {
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $_t0:ty) => {
+        impl<$($impl_gen)*> ::std::num::One for $ty where $($where_)* {
             fn one() -> Self {
                 $name(::std::num::One::one())
             }
         }
     };
 
-    (() $(pub)* struct $name:ident($_t0:ty);) => {
-        impl ::std::num::One for $name {
-            fn one() -> Self {
-                $name(::std::num::One::one())
-            }
-        }
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeOne), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeOne), ($crate::NewtypeOne! { @parsed $arg }), $($item)* }
     };
 }
 
 #[macro_export]
 macro_rules! NewtypeProduct {
-    ($arg:tt $(pub)* struct $name:ident(pub $t0:ty);) => {
-        NewtypeProduct! { $arg struct $name($t0); }
-    };
-
-    (() $(pub)* struct $name:ident($t0:ty);) => {
-        impl ::std::iter::Product<$name> for $name {
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t0:ty) => {
+        impl<$($impl_gen)*> ::std::iter::Product<$ty> for $ty where $($where_)* {
             fn product<I>(iter: I) -> Self
-            where I: Iterator<Item=$name> {
+            where I: Iterator<Item=$ty> {
                 $name(iter.map(|e| e.0).product::<$t0>())
             }
         }
     };
 
-    ((&Self) $(pub)* struct $name:ident($t0:ty);) => {
-        impl<'a> ::std::iter::Product<&'a $name> for $name {
+    (@parsed (&Self) name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t0:ty) => {
+        impl<'a, $($impl_gen)*> ::std::iter::Product<&'a $ty> for $ty where $($where_)* {
             fn product<I>(iter: I) -> Self
-            where I: Iterator<Item=&'a $name> {
+            where I: Iterator<Item=&'a $ty> {
                 $name(iter.map(|e| &e.0).product::<$t0>())
             }
         }
     };
+
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeProduct), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeProduct), ($crate::NewtypeProduct! { @parsed $arg }), $($item)* }
+    };
 }
 
 #[macro_export]
 macro_rules! NewtypeSum {
-    ($arg:tt $(pub)* struct $name:ident(pub $t0:ty);) => {
-        NewtypeSum! { $arg struct $name($t0); }
-    };
-
-    (() $(pub)* struct $name:ident($t0:ty);) => {
-        impl ::std::iter::Sum<$name> for $name {
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t0:ty) => {
+        impl<$($impl_gen)*> ::std::iter::Sum<$ty> for $ty where $($where_)* {
             fn sum<I>(iter: I) -> Self
-            where I: Iterator<Item=$name> {
+            where I: Iterator<Item=$ty> {
                 $name(iter.map(|e| e.0).sum::<$t0>())
             }
         }
     };
 
-    ((&Self) $(pub)* struct $name:ident($t0:ty);) => {
-        impl<'a> ::std::iter::Sum<&'a $name> for $name {
+    (@parsed (&Self) name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $t0:ty) => {
+        impl<'a, $($impl_gen)*> ::std::iter::Sum<&'a $ty> for $ty where $($where_)* {
             fn sum<I>(iter: I) -> Self
-            where I: Iterator<Item=&'a $name> {
+            where I: Iterator<Item=&'a $ty> {
                 $name(iter.map(|e| &e.0).sum::<$t0>())
             }
         }
     };
+
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeSum), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeSum), ($crate::NewtypeSum! { @parsed $arg }), $($item)* }
+    };
 }
 
 #[macro_export]
 macro_rules! NewtypeZero {
-    (() $(pub)* struct $name:ident(pub $_t0:ty);) => {
-        impl ::std::num::Zero for $name {
+    (@parsed () name: $name:ident, impl_generics: ($($impl_gen:tt)*), ty: $ty:ty, where: ($($where_:tt)*), inner: $_t0:ty) => {
+        impl<$($impl_gen)*> ::std::num::Zero for $ty where $($where_)* {
             fn zero() -> Self {
                 $name(::std::num::Zero::zero())
             }
         }
     };
 
-    (() $(pub)* struct $name:ident($_t0:ty);) => {
-        impl ::std::num::Zero for $name {
-            fn zero() -> Self {
-                $name(::std::num::Zero::zero())
-            }
-        }
+    (@parsed $arg:tt name: $name:ident, $($_tail:tt)*) => {
+        $crate::newtype_unsupported! { (NewtypeZero), $arg, $name }
+    };
+
+    ($arg:tt $($item:tt)*) => {
+        $crate::newtype_parse! { (NewtypeZero), ($crate::NewtypeZero! { @parsed $arg }), $($item)* }
     };
 }
@@ -0,0 +1,43 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+#[macro_use] extern crate newtype_derive;
+
+macro_attr_bundle! {
+    Arith = NewtypeAdd!, NewtypeSub!, NewtypeMul!(*), NewtypeDiv!(*)
+}
+
+macro_attr_bundle! {
+    Numeric = Copy, Clone, Eq, PartialEq, Debug, NewtypeFrom!, Arith!
+}
+
+macro_attr! {
+    #[Numeric!]
+    pub struct Metres(i32);
+}
+
+macro_attr! {
+    #[derive(Copy, Clone, Eq, PartialEq, Debug, NewtypeFrom!, Arith!)]
+    pub struct Seconds(i32);
+}
+
+#[test]
+fn test_bundle() {
+    let a = Metres::from(6);
+    let b = Metres::from(3);
+    assert_eq!(a + b, Metres::from(9));
+    assert_eq!(a - b, Metres::from(3));
+    assert_eq!(&a * &b, Metres::from(18));
+    assert_eq!(a / &b, Metres::from(2));
+
+    let c = Seconds::from(10);
+    assert_eq!(c - Seconds::from(4), Seconds::from(6));
+    assert_eq!(&c / &Seconds::from(5), Seconds::from(2));
+}
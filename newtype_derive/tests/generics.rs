@@ -0,0 +1,45 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+#[macro_use] extern crate newtype_derive;
+
+use std::fmt::{Debug, Display};
+use std::ops::{Add, AddAssign, Neg};
+
+macro_attr! {
+    #[derive(Copy, Clone, Eq, PartialEq, Debug,
+        NewtypeAdd!, NewtypeAdd!(&self), NewtypeNeg!, NewtypeAddAssign!,
+        NewtypeDeref!, NewtypeDisplay!)]
+    pub struct Id<T>(T) where T: Copy + Add<Output=T> + Neg<Output=T> + AddAssign + Display;
+}
+
+macro_attr! {
+    #[derive(Clone, Debug, NewtypeDeref!, NewtypeDerefMut!,
+        NewtypeIndex!(usize), NewtypeIndexMut!(usize))]
+    pub struct Stack<'a, T: 'a + Debug>(Vec<&'a T>);
+}
+
+#[test]
+fn test_generic_newtype() {
+    let mut a = Id(4i64);
+    a += Id(3);
+    assert_eq!(a + Id(1), Id(8));
+    assert_eq!(&a + &Id(2), Id(9));
+    assert_eq!(-a, Id(-7));
+    assert_eq!(*a, 7);
+    assert_eq!(format!("{}", a), "7");
+
+    let (x, y) = (1u8, 2u8);
+    let mut stack = Stack(vec![&x]);
+    stack.push(&y);
+    stack[0] = &y;
+    assert_eq!(*stack[0], 2);
+    assert_eq!(stack.0, vec![&2, &2]);
+}
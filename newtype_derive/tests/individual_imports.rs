@@ -0,0 +1,39 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+#[macro_use(NewtypeFrom, NewtypeAdd, NewtypeNeg, NewtypeDisplay, NewtypeDeref)] extern crate newtype_derive;
+
+// The derivations must not pick up helpers from the invoking crate.
+#[allow(unused_macros)]
+macro_rules! newtype_as_item {
+    ($($_tts:tt)*) => { compile_error!("used the wrong `newtype_as_item!`"); };
+}
+
+#[allow(unused_macros)]
+macro_rules! newtype_wrap_bin_op {
+    ($($_tts:tt)*) => { compile_error!("used the wrong `newtype_wrap_bin_op!`"); };
+}
+
+macro_attr! {
+    #[derive(Copy, Clone, Debug, PartialEq,
+        NewtypeFrom!, NewtypeAdd!(*), NewtypeNeg!, NewtypeDisplay!, NewtypeDeref!)]
+    pub struct Score(i32);
+}
+
+#[test]
+fn test_individual_imports() {
+    let a = Score::from(3);
+    let b = Score::from(4);
+    assert_eq!(a + b, Score::from(7));
+    assert_eq!(&a + &b, Score::from(7));
+    assert_eq!(-a, Score::from(-3));
+    assert_eq!(format!("{}", a), "3");
+    assert_eq!(*b, 4);
+}
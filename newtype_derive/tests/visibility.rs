@@ -0,0 +1,41 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+#[macro_use] extern crate newtype_derive;
+
+mod outer {
+    macro_attr! {
+        #[derive(Copy, Clone, Eq, PartialEq, Debug,
+            NewtypeAdd!(*), NewtypeNeg!(*), NewtypeAddAssign!,
+            NewtypeFrom!, NewtypeDeref!, NewtypeDisplay!)]
+        pub(crate) struct Id(pub(crate) i64);
+    }
+
+    macro_attr! {
+        #[derive(Clone, Debug, NewtypeFrom!, NewtypeIndex!(usize), NewtypeIndexMut!(usize))]
+        pub(crate) struct Ids(pub(super) Vec<Id>);
+    }
+}
+
+#[test]
+fn test_restricted_visibility() {
+    use outer::{Id, Ids};
+
+    let mut a = Id(4);
+    a += Id(3);
+    assert_eq!(a + &Id(1), Id(8));
+    assert_eq!(-a, Id(-7));
+    assert_eq!(*a, 7);
+    assert_eq!(format!("{}", a), "7");
+
+    let mut ids = Ids::from(vec![a]);
+    ids[0] = Id(0);
+    assert_eq!(ids.0[0], Id(0));
+}
@@ -0,0 +1,94 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+/*!
+Callback-style construction of identifiers, for use by macro derivations and attributes.
+*/
+
+/**
+Builds a new identifier out of several parts, then passes it to a callback.
+
+`macro_rules!` macros have no way of creating new identifiers, which means a derivation usually has to ask for the name of anything it defines.  Instead, a derivation can build one from the names it has been given:
+
+```ignore
+macro_attr_ident! { (callback! { args... }), case(parts...), more... }
+```
+
+This expands to `callback! { args... new_ident, more... }`; like `macro_attr_callback!`, the callback may use any kind of brackets, be a path, or be a continuation stack.  The `, more...` part is optional.
+
+Each part may be an identifier, an integer, or a string literal.  `case` determines how they are put together:
+
+- `concat`: as-is; `concat(Candy, Variants)` becomes `CandyVariants`.
+- `snake`: as lower-case words separated by underscores; `snake(is_, FruitRock)` becomes `is_fruit_rock`.
+- `upper_snake`: as upper-case words separated by underscores; `upper_snake(Candy, count)` becomes `CANDY_COUNT`.
+- `camel`: as capitalised words; `camel(candy, "variant", names)` becomes `CandyVariantNames`.
+
+Words are split at underscores and changes in case, so `HTTPServer` is treated as `HTTP` and `Server`.  The new identifier has the same span as the first identifier part, if there is one.
+
+```rust
+# #[macro_use] extern crate macro_attr;
+macro_rules! IsVariant {
+    (() $(pub)* enum $name:ident { $($var:ident),* $(,)* }) => {
+        $(
+            macro_attr_ident! { (IsVariant! { @method $name, $var, }), snake(is, $var) }
+        )*
+        macro_attr_ident! { (IsVariant! { @count $name, }), upper_snake($name, count), ($($var)*) }
+    };
+
+    (@method $name:ident, $var:ident, $method:ident) => {
+        impl $name {
+            pub fn $method(&self) -> bool {
+                match *self { $name::$var => true, _ => false }
+            }
+        }
+    };
+
+    (@count $name:ident, $count:ident, ($($var:ident)*)) => {
+        pub const $count: usize = 0 $(+ IsVariant!(@one $var))*;
+    };
+
+    (@one $_var:ident) => { 1 };
+}
+
+macro_attr! {
+    #[derive(IsVariant!)]
+    pub enum Candy { Musk, FruitRock, BoPeeps }
+}
+
+# fn main() {
+assert!(Candy::FruitRock.is_fruit_rock());
+assert!(!Candy::Musk.is_bo_peeps());
+assert_eq!(CANDY_COUNT, 3);
+# }
+```
+
+A raw identifier part such as `r#type` contributes `type`.  If the new identifier would be a keyword, a compile error is emitted instead.
+
+This requires the `ident` feature, which enables `macro-attr-proc`.  As with other procedural macros, `macro_attr_ident!` can only be used where an item is expected.
+*/
+#[macro_export]
+#[cfg(feature = "ident")]
+macro_rules! macro_attr_ident {
+    ($($input:tt)*) => {
+        $crate::__macro_attr_proc::macro_attr_ident_impl! { ($crate) $($input)* }
+    };
+}
+
+/**
+Builds a new identifier out of several parts, then passes it to a callback.
+
+This requires the `ident` feature, which is not enabled.
+*/
+#[macro_export]
+#[cfg(not(feature = "ident"))]
+macro_rules! macro_attr_ident {
+    ($($input:tt)*) => {
+        compile_error!("macro_attr_ident! requires the `ident` feature");
+    };
+}
@@ -35,7 +35,7 @@ This crate provides the `macro_attr!` macro that enables the use of custom, macr
 
 ## Compatibility
 
-`macro-attr` is compatible with Rust 1.2 and higher.
+`macro-attr` is compatible with Rust 1.30 and higher.  Releases before 0.3.0 also support Rust 1.2 through 1.29.
 
 ## Quick Example
 
@@ -48,7 +48,7 @@ To use it, make sure you link to the crate like so:
 # fn main() { let _ = Foo; }
 ```
 
-The `macro_attr!` macro should be used to wrap an entire *single* item (`enum`, `struct`, *etc.*) declaration, including its attributes (both `derive` and others).  All attributes and derivations which whose names end with `!` will be assumed to be implemented by macros, and treated accordingly.
+The `macro_attr!` macro should be used to wrap entire item (`enum`, `struct`, *etc.*) declarations, including their attributes (both `derive` and others).  Several items may be wrapped by a single invocation, and the contents of inline modules, `impl` blocks and traits are processed as well.  All attributes and derivations which whose names end with `!` will be assumed to be implemented by macros, and treated accordingly.
 
 For example:
 
@@ -69,8 +69,8 @@ trait ReprType {
 
 macro_rules! TypeName {
     // We can support any kind of item we want.
-    (() $(pub)* enum $name:ident $($tail:tt)*) => { TypeName! { @impl $name } };
-    (() $(pub)* struct $name:ident $($tail:tt)*) => { TypeName! { @impl $name } };
+    (() $vis:vis enum $name:ident $($tail:tt)*) => { TypeName! { @impl $name } };
+    (() $vis:vis struct $name:ident $($tail:tt)*) => { TypeName! { @impl $name } };
 
     // Inner rule to cut down on repetition.
     (@impl $name:ident) => {
@@ -82,7 +82,7 @@ macro_rules! TypeName {
 
 macro_rules! ReprType {
     // Note that we use a "derivation argument" here for the `$repr` type.
-    (($repr:ty) $(pub)* enum $name:ident $($tail:tt)*) => {
+    (($repr:ty) $vis:vis enum $name:ident $($tail:tt)*) => {
         impl ReprType for $name {
             type Repr = $repr;
         }
@@ -124,6 +124,13 @@ fn main() {
 */
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "ident")]
+#[doc(hidden)]
+pub extern crate macro_attr_proc as __macro_attr_proc;
+
+mod ident;
+mod parse;
+
 /**
 When given an item definition, including its attributes, this macro parses said attributes and dispatches any attributes or derivations suffixed with `!` to user-defined macros.  This allows multiple macros to process the same item.
 
@@ -142,6 +149,48 @@ Derivations parse the item and emit whatever additional definitions needed.  The
 
 Attributes receive *everything* lexically *after* themselves, and must re-emit the item.  This allows attributes to make changes to the item, drop or alter other attributes, *etc.*.  This power makes writing attribute macros more difficult, however.
 
+The input may contain any number of items.  Each is processed independently, and the items inside any inline `mod` are processed in the same way, recursively.  So are the associated items inside `impl` and `trait` blocks, which means macro attributes and derivations can be applied to individual methods, associated constants and associated types.  Macro invocations in item position are passed through unchanged, although `macro_rules!` definitions are processed like any other item.
+
+For example, here a macro attribute renames the method of an `unsafe` trait, along with its implementation:
+
+```rust
+# #[macro_use] extern crate macro_attr;
+macro_rules! rename_to {
+    (($new:ident), then $cb:tt, $(#[$($attrs:tt)*])* fn $_old:ident $($tail:tt)*) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* fn $new $($tail)* }
+    };
+}
+
+macro_attr! {
+    /// # Safety
+    ///
+    /// `count` must not exceed the length of the buffer.
+    unsafe trait Counted {
+        #[rename_to!(count)]
+        fn old_count(&self) -> usize;
+    }
+
+    unsafe impl Counted for Vec<u8> {
+        #[rename_to!(count)]
+        fn old_count(&self) -> usize { self.len() }
+    }
+}
+
+# fn main() {
+assert_eq!(vec![1u8, 2, 3].count(), 3);
+# }
+```
+
+Anything else is rejected with an error that quotes the offending input; *e.g.* ``macro_attr!: expected a supported item, found `foo bar;` ``.
+
+```compile_fail
+# #[macro_use] extern crate macro_attr;
+macro_attr! {
+    foo bar;
+}
+# fn main() {}
+```
+
 # Macro Derivations
 
 Given the following input:
@@ -165,8 +214,85 @@ Note that macro derives may be mixed with regular derives, or put in their own `
 
 A macro derivation invoked *without* arguments will be treated as though it was invoked with empty parentheses.  *i.e.* `#[derive(Name!)]` is equivalent to `#[derive(Name!())]`.
 
+Giving the same macro derivation more than once with the same arguments is an error, even if they are in different `#[derive(...)]` attributes, or one of them asks for the derivation context (see below).  The same derivation *can* be given more than once with different arguments, so long as its output doesn't conflict.  This is only checked for `struct`, `enum` and `union` items.
+
+```compile_fail
+# #[macro_use] extern crate macro_attr;
+macro_rules! Name { (() struct $name:ident;) => {}; }
+
+macro_attr! {
+    #[derive(Clone, Name!, Debug)]
+    #[derive(Name!())] // error: duplicate derivation `Name!()`
+    struct Foo;
+}
+# fn main() {}
+```
+
+```compile_fail
+# #[macro_use] extern crate macro_attr;
+macro_rules! Name {
+    (() struct $name:ident;) => {};
+    (() $ctx:tt struct $name:ident;) => {};
+}
+
+macro_attr! {
+    #[derive(Name!, Name@!)] // error: duplicate derivation `Name!()`
+    struct Foo;
+}
+# fn main() {}
+```
+
+The name of a macro derivation may also be a path, such as `#[derive(enum_derive::EnumDisplay!)]` or, inside another macro, `#[derive($crate::Name!)]`.  This means derivations don't have to be imported with `#[macro_use]` to be used.
+
+Macro derivations are not limited to `struct`, `enum` and `union` items: they can be applied to any item `macro_attr!` accepts, such as functions, `impl` blocks or `macro_rules!` definitions.  It is up to each derivation to decide what kinds of item it supports.
+
 A derivation macro may expand to any number of new items derived from the provided input.  There is no way for a derivation macro to alter the item itself (for that, use a macro attribute).
 
+If the item has any `#[cfg(...)]` attributes, its derivations are subject to the same conditions; if the item is configured out, so is everything derived from it.  Lint attributes (such as `#[allow(...)]`) and `#[deprecated]` can't be propagated the same way, since the compiler ignores these when they are attached to a macro invocation.  Instead, a derivation which takes the derivation context (see below) can emit its items through `macro_attr_inherit!`, which gives each of them the item's `#[allow(...)]` attributes, plus `#[allow(deprecated)]` if the item is deprecated.  Other derivations which refer to a deprecated item will cause deprecation warnings unless they allow them themselves.
+
+Derivations which need to cope with generic parameters or `where` clauses can forward their input to `macro_attr_parse_item!`, which breaks the item down into its name, visibility, generics and body, and passes those on to a callback.  This saves every derivation from having to parse generics by hand.
+
+Similarly, the contents of an item's body can be broken down with `macro_attr_parse_named_fields!`, `macro_attr_parse_tuple_fields!` and `macro_attr_parse_variants!`.  A derivation's own arguments (*e.g.* `Name!(positional, key = value, flag)`) can be normalised with `macro_attr_parse_args!`, which makes accepting optional keyed arguments much simpler.  Derivations which need to define new names, such as an `is_fruit_rock` method for a `FruitRock` variant, can build them with `macro_attr_ident!`, which is enabled by the `ident` feature.
+
+Derivations which are commonly used together can be given a single name with `macro_attr_bundle!`.
+
+# Derivation Context
+
+A derivation written as `Name@!` (or `Name@!(args...)`) is additionally told what else is being derived for the item, and what other attributes it has.  This is passed as a braced block between the arguments and the item:
+
+```ignore
+#[derive(Copy, Clone, Name@!(args...), Another!)]
+#[repr(u8)]
+enum Foo { A, B }
+
+// expands to, amongst other things:
+Name!((args...) {
+    derive(Copy, Clone,),
+    macros(Name@!(args...), Another!(),),
+    attrs(#[repr(u8)])
+} enum Foo { A, B });
+```
+
+The `derive` list contains the built-in derivations (including hybrid derivations, if they are being passed to the compiler).  The `macros` list contains every macro derivation on the item in `Name!(args...)` form, *including* the one being invoked, and the `attrs` list contains all other attributes that will be emitted with the item.  A derivation which only accepts this form should say so in its documentation; one which is used with both forms needs a rule for each.
+
+This allows a derivation to adapt its output: for example, by taking `self` by value when `Copy` is derived, or by relying on a companion derivation.  Note that building the context costs some of the recursion limit for every macro derivation on the item, but only if at least one of them asks for it.
+
+The context can also be given to `macro_attr_nested!`, which allows a derivation to emit items that are themselves processed by `macro_attr!`.  For such nested items, the context has a final `depth(...)` entry; see that macro for details.
+
+# Helper Attributes
+
+Derivations often need per-field or per-variant configuration.  To support this, any attribute of the form `#[macro_attr(...)]` placed on a field or variant is treated as a *helper attribute*: derivations are given the item with these attributes intact, but they are removed before the item itself is emitted, so they never reach the compiler (or any built-in `#[derive]`).
+
+```ignore
+#[derive(Debug, Labels!)]
+struct Foo {
+    #[macro_attr(rename = "x")]
+    field: i32,
+}
+```
+
+Here, `Labels!` will see the `#[macro_attr(rename = "x")]` attribute on `field` (*e.g.* in the `attrs` of `macro_attr_parse_named_fields!`), whilst the emitted `struct Foo` will not contain it.  The contents of the helper are entirely up to the derivations to interpret.
+
 # Macro Attributes
 
 When `macro_attr!` encounters an attribute suffixed with a `!` (*e.g.* `#[name!(args...)]`), it invokes the macro `name!` with everything lexically *after* that attribute.  A macro attribute is free to add to, remove from, or alter the provided input as it sees fit, before instructing `macro_attr!` to resume parsing.
@@ -237,12 +363,171 @@ struct Quxx;
 
 Note that normal attributes are automatically carried through and re-attached to the item.
 
+If a macro attribute doesn't produce an item, the result is an error quoting what it produced instead.
+
+```compile_fail
+# #[macro_use] extern crate macro_attr;
+macro_rules! unitary {
+    ((), then $resume:tt, struct $name:ident $($_tail:tt)*) => {
+        macro_attr_callback! { $resume, $name; }
+    };
+}
+
+macro_attr! {
+    #[unitary!]
+    struct Foo { field: i32 }
+}
+# fn main() {}
+```
+
+## Editing Derivations
+
+By the time a macro attribute is invoked, any `#[derive(...)]` attributes *before* it have already been collected by `macro_attr!`, so it can't see or change them.  A macro attribute written as `#[name@!(args...)]` is instead given these derivations as a single `#[derive(...)]` attribute, in front of the rest of the input.  This attribute is always present, even if it is empty.  Any derivations which remain on the item when `macro_attr!` resumes are collected again, so the macro attribute can add to, remove from or reorder them.
+
+For example, given `#[derive(Clone, Name!)] #[numeric@!] #[derive(Debug)] struct Foo(i32);`, `macro_attr!` will invoke:
+
+```ignore
+numeric! {
+    (), then $resume,
+    #[derive(Clone, Name!,)]
+    #[derive(Debug)]
+    struct Foo(i32);
+}
+```
+
+Predicates from `#[cfg(...)]` attributes are not included, and are kept regardless of what the macro attribute does.  Derivations given in `cfg_attr` are only included if the predicate holds.
+
+```rust
+# #[macro_use] extern crate macro_attr;
+macro_rules! numeric {
+    ((), then $resume:tt, #[derive($($drvs:tt)*)] $($it:tt)*) => {
+        macro_attr_callback! {
+            $resume,
+            #[derive(Copy, Clone, PartialEq, PartialOrd, $($drvs)*)]
+            $($it)*
+        }
+    };
+}
+
+macro_rules! no_derives {
+    ((), then $resume:tt, #[derive($($_drvs:tt)*)] $($it:tt)*) => {
+        macro_attr_callback! { $resume, $($it)* }
+    };
+}
+
+macro_attr! {
+    #[derive(Debug)]
+    #[numeric@!]
+    struct Metres(f64);
+}
+
+macro_attr! {
+    #[derive(Clone)]
+    #[no_derives@!]
+    #[derive(Debug)]
+    struct Label(&'static str);
+}
+
+# fn main() {
+let m = Metres(1.0);
+assert!(m < Metres(2.0) && m == m.clone());
+assert_eq!(format!("{:?}", m), "Metres(1.0)");
+assert_eq!(format!("{:?}", Label("x")), "Label(\"x\")");
+# }
+```
+
+## Emitting Other Items
+
+A macro attribute may also emit other items alongside the one it was given.  These can be passed through `macro_attr!` again using `macro_attr_nested!`.
+
+## Recursion Limit
+
 Macro attributes should be used as sparingly as possible: due to the way Rust macros work, they must expand recursively in sequence, which can quickly consume the available macro recursion limit.  This limit can be raised, but it makes for a less-than-ideal user experience if you are authoring macros to be used by others.
+
+# Conditional Derivations and Attributes
+
+Derivations and macro attributes may also be wrapped in `cfg_attr`, in which case they are only expanded if the predicate holds.  This makes it possible to tie optional derivations to Cargo features:
+
+```ignore
+#[derive(Debug)]
+#[cfg_attr(feature = "display", derive(EnumDisplay!))]
+#[cfg_attr(test, rename_to!(Quux))]
+enum Foo { A, B }
+```
+
+Any other attributes inside a `cfg_attr` are unaffected.
+
+# Field and Variant Macro Attributes
+
+Macro attributes can also be attached to the fields of a `struct` or `union`, and to the variants (or fields of variants) of an `enum`.  These are applied before any derivations are expanded, so derivations see the rewritten item.
+
+They are invoked in the same way as macro attributes on items, and may also name the macro by path (as in `#[::my_crate::redact!]`).  The difference is that they receive the field or variant they are attached to *and everything after it* in the same list.  To make these easier to match, this input always ends with a comma; if that comma wasn't in the original item, it is removed again afterwards.  For example, the following replaces the type of a named field:
+
+```rust
+# #[macro_use] extern crate macro_attr;
+macro_rules! redact {
+    (
+        (), then $resume:tt,
+        $(#[$($attrs:tt)*])* $vis:vis $name:ident: $_ty:ty, $($tail:tt)*
+    ) => {
+        macro_attr_callback! { $resume, $(#[$($attrs)*])* $vis $name: (), $($tail)* }
+    };
+}
+
+macro_attr! {
+    #[derive(Debug)]
+    struct Login {
+        user: &'static str,
+        #[redact!]
+        password: &'static str,
+    }
+}
+
+# fn main() {
+let login = Login { user: "admin", password: () };
+assert_eq!(format!("{:?}", login), "Login { user: \"admin\", password: () }");
+# }
+```
+
+As with macro attributes on items, these must be expanded recursively, one at a time.  Worse, finding them requires walking the entire item, so an item with field or variant macro attributes takes considerably more of the recursion limit to expand than one without.
+
+# Hybrid Derivations and Attributes
+
+A derivation written as `Name~!`, or an attribute written as `#[name~!(...)]`, is a *hybrid*: it is implemented both as a procedural macro and as a macro-by-example.  If procedural macro support is enabled, `macro_attr!` passes it through as an ordinary `#[derive(Name)]` or `#[name(...)]`; otherwise, it is invoked as a derivation or macro attribute as described above.
+
+Procedural macro support is controlled by the `macro-attr-proc` feature, which is enabled by default.  A crate shipping both implementations can export the one `macro_attr!` will expect under the shared name by wrapping its exports in `macro_attr_if_proc_macros!`, which expands either its `proc_macros` or its `fallback` items to match.  See `tests/derive-name-macro` in the repository for an example.
+
+# Tracing
+
+Adding `#[macro_attr_trace(NAME)]` to an item makes `macro_attr!` emit a `&'static str` constant called `NAME` alongside it, describing how the item was expanded.  It records the input to each macro attribute that runs after the marker, the arguments and item passed to each derivation, and the attributes and item that were finally emitted.  A visibility may be given before the name, as in `#[macro_attr_trace(pub TRACE)]`.  The marker itself is not emitted.
+
+```rust
+# #[macro_use] extern crate macro_attr;
+macro_rules! Name {
+    (() $(pub)* struct $name:ident;) => {
+        impl $name { fn name() -> &'static str { stringify!($name) } }
+    };
+}
+
+macro_attr! {
+    #[macro_attr_trace(FOO_TRACE)]
+    #[derive(Clone, Name!)]
+    struct Foo;
+}
+
+# fn main() {
+assert_eq!(Foo::name(), "Foo");
+assert!(FOO_TRACE.contains("derivation: Name"));
+println!("{}", FOO_TRACE);
+# }
+```
+
+The exact text depends on how the compiler turns tokens back into strings, which may change between releases.  It is intended for debugging and snapshot tests, not for parsing.  Note that the trace only covers the item itself; macro attributes on fields and variants are not recorded.
 */
 #[macro_export]
 macro_rules! macro_attr {
     ($($item:tt)*) => {
-        macro_attr_impl! { $($item)* }
+        $crate::macro_attr_impl! { @items ($($item)*) }
     };
 }
 
@@ -265,7 +550,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         const $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (const $($it)*)
@@ -276,7 +561,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         enum $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (enum $($it)*)
@@ -287,7 +572,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         extern $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (extern $($it)*)
@@ -298,7 +583,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         fn $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (fn $($it)*)
@@ -309,7 +594,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         impl $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (impl $($it)*)
@@ -320,7 +605,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         mod $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (mod $($it)*)
@@ -331,7 +616,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         pub $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (pub $($it)*)
@@ -342,7 +627,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         static $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (static $($it)*)
@@ -353,7 +638,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         struct $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (struct $($it)*)
@@ -364,7 +649,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         trait $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (trait $($it)*)
@@ -375,7 +660,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         type $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (type $($it)*)
@@ -386,7 +671,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         use $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*), (), (),
             (use $($it)*)
@@ -395,6 +680,167 @@ macro_rules! macro_attr_impl {
 
     /*
 
+    Anything else that starts with an identifier is only an item if `@item_kw` says so.  Visibilities which have already been captured by a `vis` fragment (*e.g.* by a macro attribute) can't be matched against `pub` above, so they're handled the same way.  These are separate rules because an empty `vis` fragment would still show up as a stray space in the error message.
+
+    */
+    (
+        $(#[$($attrs:tt)*])*
+        $kw:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_kw $kw,
+            (@split_attrs ($(#[$($attrs)*],)*), (), (), ($kw $($it)*)),
+            (@unsupported_item ($(#[$($attrs)*])* $kw $($it)*))
+        }
+    };
+
+    (
+        $(#[$($attrs:tt)*])*
+        $vis:vis $kw:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_kw $kw,
+            (@split_attrs ($(#[$($attrs)*],)*), (), (), ($vis $kw $($it)*)),
+            (@unsupported_item ($(#[$($attrs)*])* $vis $kw $($it)*))
+        }
+    };
+
+    /*
+
+    # `@item_kw`
+
+    Continues with the first group if the given identifier can start an item (after the visibility), or the second if not.
+
+    */
+    (@item_kw const, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw enum, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw extern, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw fn, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw impl, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw mod, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw static, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw struct, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw trait, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw type, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw use, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw union, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw unsafe, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw async, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw default, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw auto, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw macro_rules, ($($then:tt)*), $_else:tt) => { $crate::macro_attr_impl! { $($then)* } };
+    (@item_kw $_kw:ident, $_then:tt, ($($else:tt)*)) => { $crate::macro_attr_impl! { $($else)* } };
+
+    /*
+
+    # `@items`
+
+    Splits the input into individual items, each of which is then fed back through the primary invocation forms.  An item ends at the first top-level `;` or `{...}` group, with the exception of `const`, `static`, `type` and `use` items, which can contain braces (*e.g.* `use a::{b, c};`) and so only end at a `;`.
+
+    Macro invocations in item position are passed through untouched; `macro_rules!` definitions are items in their own right, so they are not.
+
+    An item's attributes are all moved across in a single step, so that long doc comments don't eat the recursion limit.  This works because the rule can only leave the repetition at an identifier (such as `pub` or `struct`), which can't be confused with the `#` of another attribute.  An item which starts with `::` is handled one attribute at a time.
+
+    */
+    (@items ()) => {};
+
+    (@items ($($tail:tt)+)) => {
+        $crate::macro_attr_impl! { @items_head (), ($($tail)*) }
+    };
+
+    (@items_head (), ($(#[$($attrs:tt)*])+ $next:ident $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_head ($(#[$($attrs)*])*), ($next $($tail)*) }
+    };
+
+    (@items_head ($($item:tt)*), (#[$($attr:tt)*] $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_head ($($item)* #[$($attr)*]), ($($tail)*) }
+    };
+
+    (@items_head ($($item:tt)*), (pub ($($vis:tt)*) $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_head ($($item)* pub ($($vis)*)), ($($tail)*) }
+    };
+
+    (@items_head ($($item:tt)*), (pub $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_head ($($item)* pub), ($($tail)*) }
+    };
+
+    (@items_head $item:tt, (const fn $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (process, any), $item, (const fn $($tail)*) }
+    };
+    (@items_head $item:tt, (const unsafe $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (process, any), $item, (const unsafe $($tail)*) }
+    };
+    (@items_head $item:tt, (const async $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (process, any), $item, (const async $($tail)*) }
+    };
+    (@items_head $item:tt, (const extern $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (process, any), $item, (const extern $($tail)*) }
+    };
+
+    (@items_head $item:tt, (const $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (process, semi), $item, (const $($tail)*) }
+    };
+
+    (@items_head $item:tt, (static $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (process, semi), $item, (static $($tail)*) }
+    };
+
+    (@items_head $item:tt, (type $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (process, semi), $item, (type $($tail)*) }
+    };
+
+    (@items_head $item:tt, (use $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (process, semi), $item, (use $($tail)*) }
+    };
+
+    (@items_head $item:tt, (macro_rules ! $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (process, any), $item, (macro_rules ! $($tail)*) }
+    };
+
+    (@items_head $item:tt, ($mac:ident ! $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (pass, any), $item, ($mac ! $($tail)*) }
+    };
+
+    (@items_head $item:tt, ($seg:ident :: $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (pass, any), $item, ($seg :: $($tail)*) }
+    };
+
+    (@items_head $item:tt, (:: $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body (pass, any), $item, (:: $($tail)*) }
+    };
+
+    (@items_head $item:tt, $tail:tt) => {
+        $crate::macro_attr_impl! { @items_body (process, any), $item, $tail }
+    };
+
+    (@items_body ($how:ident, any), ($($item:tt)*), ({ $($body:tt)* } $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_emit $how, ($($item)* { $($body)* }), ($($tail)*) }
+    };
+
+    (@items_body ($how:ident, $_ends:ident), ($($item:tt)*), (; $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_emit $how, ($($item)* ;), ($($tail)*) }
+    };
+
+    (@items_body ($how:ident, $_ends:ident), $item:tt, ()) => {
+        $crate::macro_attr_impl! { @items_emit $how, $item, () }
+    };
+
+    (@items_body $mode:tt, ($($item:tt)*), ($next:tt $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @items_body $mode, ($($item)* $next), ($($tail)*) }
+    };
+
+    (@items_emit process, ($($item:tt)*), $tail:tt) => {
+        $crate::macro_attr_impl! { $($item)* }
+        $crate::macro_attr_impl! { @items $tail }
+    };
+
+    (@items_emit pass, ($($item:tt)*), $tail:tt) => {
+        $($item)*
+        $crate::macro_attr_impl! { @items $tail }
+    };
+
+    /*
+
     # `@split_attrs`
 
     This is responsible for dividing all attributes on an item into two groups:
@@ -412,12 +858,10 @@ macro_rules! macro_attr_impl {
         $derives:tt,
         $it:tt
     ) => {
-        macro_attr_impl! {
-            @split_derive_attrs
-            { $non_derives, $it },
-            $derives,
-            (),
-            ()
+        $crate::macro_attr_impl! {
+            @field_attrs
+            ($non_derives, $derives),
+            $it, $it
         }
     };
 
@@ -428,7 +872,7 @@ macro_rules! macro_attr_impl {
         ($($derives:tt)*),
         $it:tt
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -437,39 +881,98 @@ macro_rules! macro_attr_impl {
         }
     };
 
+    /*
+
+    ## Tracing
+
+    `#[macro_attr_trace(...)]` turns on tracing for the rest of the item.  The trace is kept at the front of the list of derivations, since that is carried through macro attributes untouched.  Each macro attribute adds a record of its input to the trace it passes along to `@split_attrs_resume`.
+
+    */
+    (
+        @split_attrs
+        (#[macro_attr_trace($($decl:tt)*)], $(#[$($attrs:tt)*],)*),
+        $non_derives:tt,
+        ($($derives:tt)*),
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs
+            ($(#[$($attrs)*],)*),
+            $non_derives,
+            (@trace ($($decl)*) (), $($derives)*),
+            $it
+        }
+    };
+
+    /*
+
+    ## Nesting Depth
+
+    `macro_attr_nested!` marks the items it is given with `#[macro_attr_depth(...)]`.  This is also kept in the list of derivations, where both macro attributes (through their callback) and `@split_derive_attrs` can find it.
+
+    */
+    (
+        @split_attrs
+        (#[macro_attr_depth($($depth:tt)*)], $(#[$($attrs:tt)*],)*),
+        $non_derives:tt,
+        ($($derives:tt)*),
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs
+            ($(#[$($attrs)*],)*),
+            $non_derives,
+            (@depth ($($depth)*), $($derives)*),
+            $it
+        }
+    };
+
     (
         @split_attrs
         (#[$mac_attr:ident!], $(#[$($attrs:tt)*],)*),
         $non_derives:tt,
-        $derives:tt,
+        (@trace $decl:tt ($($records:tt)*), $($derives:tt)*),
         ($($it:tt)*)
     ) => {
         $mac_attr! {
             (),
-            then (macro_attr_impl! {
+            then ($crate::macro_attr_impl! {
                 @split_attrs_resume
                 $non_derives,
-                $derives,
+                (
+                    @trace $decl (
+                        $($records)*
+                        "macro attribute: ", stringify!(#[$mac_attr!]), "\n",
+                        "input: ", stringify!($(#[$($attrs)*])* $($it)*), "\n",
+                    ),
+                    $($derives)*
+                ),
             }),
             $(#[$($attrs)*])*
             $($it)*
         }
     };
 
-
     (
         @split_attrs
         (#[$mac_attr:ident!($($attr_args:tt)*)], $(#[$($attrs:tt)*],)*),
         $non_derives:tt,
-        $derives:tt,
+        (@trace $decl:tt ($($records:tt)*), $($derives:tt)*),
         ($($it:tt)*)
     ) => {
         $mac_attr! {
             ($($attr_args)*),
-            then (macro_attr_impl! {
+            then ($crate::macro_attr_impl! {
                 @split_attrs_resume
                 $non_derives,
-                $derives,
+                (
+                    @trace $decl (
+                        $($records)*
+                        "macro attribute: ", stringify!(#[$mac_attr!($($attr_args)*)]), "\n",
+                        "input: ", stringify!($(#[$($attrs)*])* $($it)*), "\n",
+                    ),
+                    $($derives)*
+                ),
             }),
             $(#[$($attrs)*])*
             $($it)*
@@ -478,25 +981,160 @@ macro_rules! macro_attr_impl {
 
     (
         @split_attrs
-        (#[$mac_attr:ident~!], $(#[$($attrs:tt)*],)*),
-        ($($non_derives:tt)*),
+        (#[$mac_attr:ident!], $(#[$($attrs:tt)*],)*),
+        $non_derives:tt,
         $derives:tt,
         ($($it:tt)*)
     ) => {
-        macro_attr_if_proc_macros! {
-            proc_macros: {
-                macro_attr_impl! {
-                    @split_attrs
-                    ($(#[$($attrs)*],)*),
-                    ($($non_derives)* #[$mac_attr],),
-                    $derives,
-                    $($it)*
+        $mac_attr! {
+            (),
+            then ($crate::macro_attr_impl! {
+                @split_attrs_resume
+                $non_derives,
+                $derives,
+            }),
+            $(#[$($attrs)*])*
+            $($it)*
+        }
+    };
+
+
+    (
+        @split_attrs
+        (#[$mac_attr:ident!($($attr_args:tt)*)], $(#[$($attrs:tt)*],)*),
+        $non_derives:tt,
+        $derives:tt,
+        ($($it:tt)*)
+    ) => {
+        $mac_attr! {
+            ($($attr_args)*),
+            then ($crate::macro_attr_impl! {
+                @split_attrs_resume
+                $non_derives,
+                $derives,
+            }),
+            $(#[$($attrs)*])*
+            $($it)*
+        }
+    };
+
+    /*
+
+    ## Macro Attributes With Derivations
+
+    Macro attributes written as `#[name@!]` are given the derivations collected so far, as a `#[derive(...)]` attribute at the front of their input.  When `macro_attr!` resumes, these will be collected again along with any changes the macro attribute made.
+
+    The list of derivations also contains the markers left by `#[cfg]`, `#[macro_attr_trace]` and `#[macro_attr_depth]`, so these have to be separated out first; they stay behind in the callback.  This has to be done a token at a time, since a derivation can consist of any number of tokens.
+
+    */
+    (
+        @split_attrs
+        (#[$mac_attr:ident@!], $(#[$($attrs:tt)*],)*),
+        $non_derives:tt,
+        $derives:tt,
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_drv_markers
+            $derives, (), (),
+            ($mac_attr, (), ($(#[$($attrs)*])*), $non_derives, $it)
+        }
+    };
+
+    (
+        @split_attrs
+        (#[$mac_attr:ident@!($($attr_args:tt)*)], $(#[$($attrs:tt)*],)*),
+        $non_derives:tt,
+        $derives:tt,
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_drv_markers
+            $derives, (), (),
+            ($mac_attr, ($($attr_args)*), ($(#[$($attrs)*])*), $non_derives, $it)
+        }
+    };
+
+    (@split_drv_markers (@cfg $pred:tt, $($tail:tt)*), ($($markers:tt)*), $drvs:tt, $fixed:tt) => {
+        $crate::macro_attr_impl! { @split_drv_markers ($($tail)*), ($($markers)* @cfg $pred,), $drvs, $fixed }
+    };
+
+    (@split_drv_markers (@depth $depth:tt, $($tail:tt)*), ($($markers:tt)*), $drvs:tt, $fixed:tt) => {
+        $crate::macro_attr_impl! { @split_drv_markers ($($tail)*), ($($markers)* @depth $depth,), $drvs, $fixed }
+    };
+
+    (@split_drv_markers (@trace $decl:tt $records:tt, $($tail:tt)*), ($($markers:tt)*), $drvs:tt, $fixed:tt) => {
+        $crate::macro_attr_impl! { @split_drv_markers ($($tail)*), ($($markers)* @trace $decl $records,), $drvs, $fixed }
+    };
+
+    (@split_drv_markers ($next:tt $($tail:tt)*), $markers:tt, ($($drvs:tt)*), $fixed:tt) => {
+        $crate::macro_attr_impl! { @split_drv_markers ($($tail)*), $markers, ($($drvs)* $next), $fixed }
+    };
+
+    (
+        @split_drv_markers
+        (), (@trace $decl:tt ($($records:tt)*), $($markers:tt)*), ($($drvs:tt)*),
+        ($mac_attr:ident, ($($attr_args:tt)*), ($($attrs:tt)*), $non_derives:tt, ($($it:tt)*))
+    ) => {
+        $mac_attr! {
+            ($($attr_args)*),
+            then ($crate::macro_attr_impl! {
+                @split_attrs_resume
+                $non_derives,
+                (
+                    @trace $decl (
+                        $($records)*
+                        "macro attribute: ", stringify!(#[$mac_attr@!($($attr_args)*)]), "\n",
+                        "input: ", stringify!(#[derive($($drvs)*)] $($attrs)* $($it)*), "\n",
+                    ),
+                    $($markers)*
+                ),
+            }),
+            #[derive($($drvs)*)]
+            $($attrs)*
+            $($it)*
+        }
+    };
+
+    (
+        @split_drv_markers
+        (), $markers:tt, ($($drvs:tt)*),
+        ($mac_attr:ident, ($($attr_args:tt)*), ($($attrs:tt)*), $non_derives:tt, ($($it:tt)*))
+    ) => {
+        $mac_attr! {
+            ($($attr_args)*),
+            then ($crate::macro_attr_impl! {
+                @split_attrs_resume
+                $non_derives,
+                $markers,
+            }),
+            #[derive($($drvs)*)]
+            $($attrs)*
+            $($it)*
+        }
+    };
+
+    (
+        @split_attrs
+        (#[$mac_attr:ident~!], $(#[$($attrs:tt)*],)*),
+        ($($non_derives:tt)*),
+        $derives:tt,
+        ($($it:tt)*)
+    ) => {
+        $crate::macro_attr_if_proc_macros! {
+            proc_macros: {
+                $crate::macro_attr_impl! {
+                    @split_attrs
+                    ($(#[$($attrs)*],)*),
+                    ($($non_derives)* #[$mac_attr],),
+                    $derives,
+                    ($($it)*)
                 }
             }
             fallback: {
                 $mac_attr! {
                     (),
-                    then (macro_attr_impl! {
+                    then ($crate::macro_attr_impl! {
                         @split_attrs_resume
                         ($($non_derives)*),
                         $derives,
@@ -515,20 +1153,20 @@ macro_rules! macro_attr_impl {
         $derives:tt,
         ($($it:tt)*)
     ) => {
-        macro_attr_if_proc_macros! {
+        $crate::macro_attr_if_proc_macros! {
             proc_macros: {
-                macro_attr_impl! {
+                $crate::macro_attr_impl! {
                     @split_attrs
                     ($(#[$($attrs)*],)*),
                     ($($non_derives)* #[$mac_attr($($attr_args)*)],),
                     $derives,
-                    $($it)*
+                    ($($it)*)
                 }
             }
             fallback: {
                 $mac_attr! {
                     ($($attr_args)*),
-                    then (macro_attr_impl! {
+                    then ($crate::macro_attr_impl! {
                         @split_attrs_resume
                         ($($non_derives)*),
                         $derives,
@@ -540,6 +1178,128 @@ macro_rules! macro_attr_impl {
         }
     };
 
+    /*
+
+    ## `cfg`
+
+    Derivations have to be gated on the same conditions as the item itself; otherwise they would refer to an item that doesn't exist.  So, as well as keeping it with the other attributes, we make a note of each `cfg` predicate in the list of derivations, where `@split_derive_attrs` can pick it up.
+
+    */
+    (
+        @split_attrs
+        (#[cfg($($pred:tt)*)], $(#[$($attrs:tt)*],)*),
+        ($($non_derives:tt)*),
+        ($($derives:tt)*),
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs
+            ($(#[$($attrs)*],)*),
+            ($($non_derives)* #[cfg($($pred)*)],),
+            ($($derives)* @cfg ($($pred)*),),
+            $it
+        }
+    };
+
+    /*
+
+    ## Conditional Attributes
+
+    A `#[cfg_attr(pred, attrs...)]` may contain derivations or macro attributes, which we can only expand if `pred` holds.  Since there's no way to evaluate the predicate here, we instead split its contents into separate attributes, then expand *both* outcomes, each gated on the predicate.  The compiler strips whichever one is inactive *before* it is expanded, so this doesn't cost anything beyond the splitting itself.
+
+    */
+    (
+        @split_attrs
+        (#[cfg_attr($pred:meta, $($cfg_attrs:tt)*)], $(#[$($attrs:tt)*],)*),
+        $non_derives:tt,
+        $derives:tt,
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_cfg_attr
+            ($pred), (), (), ($($cfg_attrs)*),
+            (($(#[$($attrs)*],)*), $non_derives, $derives, $it)
+        }
+    };
+
+    /*
+
+    Long runs of "plain" attributes (especially doc comments, which arrive as one `#[doc = "..."]` attribute per line) would otherwise cost one recursion step each.  Word and name-value attributes can never be derivations or macro attributes, so we move them across eight at a time.
+
+    */
+    (
+        @split_attrs
+        (
+            #[$n0:ident $(= $v0:tt)*],
+            #[$n1:ident $(= $v1:tt)*],
+            #[$n2:ident $(= $v2:tt)*],
+            #[$n3:ident $(= $v3:tt)*],
+            #[$n4:ident $(= $v4:tt)*],
+            #[$n5:ident $(= $v5:tt)*],
+            #[$n6:ident $(= $v6:tt)*],
+            #[$n7:ident $(= $v7:tt)*],
+            $(#[$($attrs:tt)*],)*
+        ),
+        ($($non_derives:tt)*),
+        $derives:tt,
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs
+            ($(#[$($attrs)*],)*),
+            (
+                $($non_derives)*
+                #[$n0 $(= $v0)*],
+                #[$n1 $(= $v1)*],
+                #[$n2 $(= $v2)*],
+                #[$n3 $(= $v3)*],
+                #[$n4 $(= $v4)*],
+                #[$n5 $(= $v5)*],
+                #[$n6 $(= $v6)*],
+                #[$n7 $(= $v7)*],
+            ),
+            $derives,
+            $it
+        }
+    };
+
+    /*
+
+    `#[allow(...)]` and `#[deprecated]` are kept as plain tokens, rather than being captured as a `meta`, so that `macro_attr_inherit!` can still recognise them in the derivation context.
+
+    */
+    (
+        @split_attrs
+        (#[allow($($lints:tt)*)], $(#[$($attrs:tt)*],)*),
+        ($($non_derives:tt)*),
+        $derives:tt,
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs
+            ($(#[$($attrs)*],)*),
+            ($($non_derives)* #[allow($($lints)*)],),
+            $derives,
+            $it
+        }
+    };
+
+    (
+        @split_attrs
+        (#[deprecated $($args:tt)*], $(#[$($attrs:tt)*],)*),
+        ($($non_derives:tt)*),
+        $derives:tt,
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_attrs
+            ($(#[$($attrs)*],)*),
+            ($($non_derives)* #[deprecated $($args)*],),
+            $derives,
+            $it
+        }
+    };
+
     (
         @split_attrs
         (#[$new_attr:meta], $(#[$($attrs:tt)*],)*),
@@ -547,7 +1307,7 @@ macro_rules! macro_attr_impl {
         $derives:tt,
         $it:tt
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             ($($non_derives)* #[$new_attr],),
@@ -557,6 +1317,73 @@ macro_rules! macro_attr_impl {
     };
 
 
+    /*
+
+    # `@split_cfg_attr`
+
+    Splits the contents of a `cfg_attr` into individual attributes at each top-level comma, then forks the rest of the expansion on its predicate.
+
+    */
+    (
+        @split_cfg_attr
+        $pred:tt, ($($done:tt)*), ($($cur:tt)+), (, $($tail:tt)*),
+        $fixed:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_cfg_attr
+            $pred, ($($done)* #[$($cur)+],), (), ($($tail)*),
+            $fixed
+        }
+    };
+
+    (
+        @split_cfg_attr
+        $pred:tt, $done:tt, ($($cur:tt)*), ($next:tt $($tail:tt)*),
+        $fixed:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_cfg_attr
+            $pred, $done, ($($cur)* $next), ($($tail)*),
+            $fixed
+        }
+    };
+
+    (
+        @split_cfg_attr
+        $pred:tt, ($($done:tt)*), ($($cur:tt)+), (),
+        $fixed:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_cfg_attr
+            $pred, ($($done)* #[$($cur)+],), (), (),
+            $fixed
+        }
+    };
+
+    (
+        @split_cfg_attr
+        ($pred:meta), ($($done:tt)*), (), (),
+        (($($attrs:tt)*), $non_derives:tt, $derives:tt, $it:tt)
+    ) => {
+        #[cfg($pred)]
+        $crate::macro_attr_impl! {
+            @split_attrs
+            ($($done)* $($attrs)*),
+            $non_derives,
+            $derives,
+            $it
+        }
+
+        #[cfg(not($pred))]
+        $crate::macro_attr_impl! {
+            @split_attrs
+            ($($attrs)*),
+            $non_derives,
+            $derives,
+            $it
+        }
+    };
+
     /*
 
     # `@split_attrs_resume`
@@ -572,7 +1399,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         const $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -588,7 +1415,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         enum $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -604,7 +1431,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         extern $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -620,7 +1447,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         fn $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -636,7 +1463,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         impl $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -652,7 +1479,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         mod $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -668,7 +1495,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         pub $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -684,7 +1511,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         static $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -700,7 +1527,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         struct $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -716,7 +1543,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         trait $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -732,7 +1559,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         type $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -748,7 +1575,7 @@ macro_rules! macro_attr_impl {
         $(#[$($attrs:tt)*])*
         use $($it:tt)*
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_attrs
             ($(#[$($attrs)*],)*),
             $non_derives,
@@ -757,54 +1584,302 @@ macro_rules! macro_attr_impl {
         }
     };
 
+    (
+        @split_attrs_resume
+        $non_derives:tt,
+        $derives:tt,
+        $(#[$($attrs:tt)*])*
+        $kw:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_kw $kw,
+            (@split_attrs ($(#[$($attrs)*],)*), $non_derives, $derives, ($kw $($it)*)),
+            (@unsupported_output ($(#[$($attrs)*])* $kw $($it)*))
+        }
+    };
+
+    (
+        @split_attrs_resume
+        $non_derives:tt,
+        $derives:tt,
+        $(#[$($attrs:tt)*])*
+        $vis:vis $kw:ident $($it:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @item_kw $kw,
+            (@split_attrs ($(#[$($attrs)*],)*), $non_derives, $derives, ($vis $kw $($it)*)),
+            (@unsupported_output ($(#[$($attrs)*])* $vis $kw $($it)*))
+        }
+    };
+
 
     /*
 
-    # `@split_derive_attrs`
+    # `@field_attrs`
 
-    This is responsible for taking the list of derivation attributes and splitting them into "built-in" and "custom" groups.
+    Applies macro attributes attached to fields and variants.  These are invoked just like item-level macro attributes, except that they receive the field or variant they're attached to *and everything after it* in the same list, and must pass the (possibly altered) list on to the continuation.  This happens before any derivations are expanded, so derivations see the rewritten item.
 
-    A custom attribute is any which has a `!` after the name, like a macro.
-    */
+    Finding these attributes requires walking the whole item one token at a time, which is far too expensive to do to every item.  Instead, a copy of the item is first broken apart using fragments to pull out all of the field and variant attributes in a single step, and only if one of *those* is a macro attribute do we do the expensive walk.
 
-    (@split_derive_attrs
-        { ($(#[$($non_derives:tt)*],)*), ($($it:tt)*) },
-        ($(,)*), (), ($($user_drvs:tt)*)
-    ) => {
-        macro_attr_impl! {
-            @as_item
-            $(#[$($non_derives)*])*
-            $($it)*
-        }
+    */
+    (@field_attrs $fixed:tt, ($_vis:vis struct $_name:ident $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @field_attrs_head $fixed, ($($tail)*), $it }
+    };
 
-        macro_attr_impl! {
-            @expand_user_drvs
-            ($($user_drvs)*), ($($it)*)
-        }
+    (@field_attrs $fixed:tt, ($_vis:vis union $_name:ident $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @field_attrs_head $fixed, ($($tail)*), $it }
     };
 
-    (@split_derive_attrs
-        { ($(#[$($non_derives:tt)*],)*), ($($it:tt)*) },
-        ($(,)*), ($($bi_drvs:ident,)+), ($($user_drvs:tt)*)
-    ) => {
-        macro_attr_impl! {
-            @as_item
-            #[derive($($bi_drvs,)+)]
-            $(#[$($non_derives)*])*
-            $($it)*
-        }
+    (@field_attrs $fixed:tt, ($_vis:vis enum $_name:ident $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @field_attrs_head_enum $fixed, ($($tail)*), $it }
+    };
 
-        macro_attr_impl! {
-            @expand_user_drvs
-            ($($user_drvs)*), ($($it)*)
-        }
+    (@field_attrs $fixed:tt, $_copy:tt, $it:tt) => {
+        $crate::macro_attr_impl! { @field_attrs_done $fixed, $it }
     };
 
-    (@split_derive_attrs
-        $fixed:tt,
-        (,, $($tail:tt)*), $bi_drvs:tt, $user_drvs:tt
+    (
+        @field_attrs_head $fixed:tt,
+        ({ $($(#[$($attrs:tt)*])* $_vis:vis $_name:ident: $_ty:ty),* $(,)* }),
+        $it:tt
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! { @field_attrs_check $fixed, ($($(#[$($attrs)*])*)*), $it }
+    };
+
+    (
+        @field_attrs_head $fixed:tt,
+        (($($(#[$($attrs:tt)*])* $_vis:vis $_ty:ty),* $(,)*); ),
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! { @field_attrs_check $fixed, ($($(#[$($attrs)*])*)*), $it }
+    };
+
+    (
+        @field_attrs_head $fixed:tt,
+        (($($(#[$($attrs:tt)*])* $_vis:vis $_ty:ty),* $(,)*) where $($_tail:tt)*),
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! { @field_attrs_check $fixed, ($($(#[$($attrs)*])*)*), $it }
+    };
+
+    (@field_attrs_head $fixed:tt, (), $it:tt) => {
+        $crate::macro_attr_impl! { @field_attrs_done $fixed, $it }
+    };
+
+    (@field_attrs_head $fixed:tt, ($_next:tt $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @field_attrs_head $fixed, ($($tail)*), $it }
+    };
+
+    (
+        @field_attrs_head_enum $fixed:tt,
+        ({ $(
+            $(#[$($attrs:tt)*])* $_name:ident
+            $(($($(#[$($tattrs:tt)*])* $_tty:ty),* $(,)*))*
+            $({$($(#[$($nattrs:tt)*])* $_nname:ident: $_nty:ty),* $(,)*})*
+            $(= $_disc:expr)*
+        ),* $(,)* }),
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @field_attrs_check $fixed,
+            ($(
+                $(#[$($attrs)*])*
+                $($($(#[$($tattrs)*])*)*)*
+                $($($(#[$($nattrs)*])*)*)*
+            )*),
+            $it
+        }
+    };
+
+    (@field_attrs_head_enum $fixed:tt, ($_next:tt $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @field_attrs_head_enum $fixed, ($($tail)*), $it }
+    };
+
+    /*
+
+    As in `@split_attrs`, runs of plain attributes (mostly doc comments) are skipped eight at a time.
+
+    */
+    (@field_attrs_check $fixed:tt, (), $it:tt) => {
+        $crate::macro_attr_impl! { @field_attrs_done $fixed, $it }
+    };
+
+    (@field_attrs_check $fixed:tt, (#[$($_mac:tt)::+ ! $($_args:tt)*] $($_tail:tt)*), ($($it:tt)*)) => {
+        $crate::macro_attr_impl! { @field_attrs_walk $fixed, (), (,), ($($it)*) -> () }
+    };
+
+    (@field_attrs_check $fixed:tt, (#[:: $($_mac:tt)::+ ! $($_args:tt)*] $($_tail:tt)*), ($($it:tt)*)) => {
+        $crate::macro_attr_impl! { @field_attrs_walk $fixed, (), (,), ($($it)*) -> () }
+    };
+
+    (
+        @field_attrs_check $fixed:tt,
+        (
+            #[$_n0:ident $(= $_v0:tt)*] #[$_n1:ident $(= $_v1:tt)*]
+            #[$_n2:ident $(= $_v2:tt)*] #[$_n3:ident $(= $_v3:tt)*]
+            #[$_n4:ident $(= $_v4:tt)*] #[$_n5:ident $(= $_v5:tt)*]
+            #[$_n6:ident $(= $_v6:tt)*] #[$_n7:ident $(= $_v7:tt)*]
+            $($tail:tt)*
+        ),
+        $it:tt
+    ) => {
+        $crate::macro_attr_impl! { @field_attrs_check $fixed, ($($tail)*), $it }
+    };
+
+    (@field_attrs_check $fixed:tt, (#[$($_attr:tt)*] $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @field_attrs_check $fixed, ($($tail)*), $it }
+    };
+
+    /*
+
+    This is the expensive walk.  Groups are descended into by pushing a frame of `(delimiter, remaining input, comma, output so far)` onto a stack, which is popped when the end of the group is reached.
+
+    So that macro attributes don't have to worry about whether the field or variant they're attached to is the last one in the list, a comma is added to the end of their input.  `$comma` is the comma still to be added in the current group: it starts out as `(,)`, and becomes `()` once the first macro attribute in the group has been given it.  That comma is then the last token in the group, so it's dropped again when the walk reaches it; nothing else is touched.
+
+    */
+    (
+        @field_attrs_walk $fixed:tt, $stack:tt, ($($comma:tt)*),
+        (#[$($mac:tt)::+ ! $(($($args:tt)*))*] $($tail:tt)*) -> $out:tt
+    ) => {
+        $($mac)::+ ! {
+            ($($($args)*)*),
+            then ($crate::macro_attr_impl! { @field_attrs_resume $fixed, $stack, $out, }),
+            $($tail)* $($comma)*
+        }
+    };
+
+    (
+        @field_attrs_walk $fixed:tt, $stack:tt, ($($comma:tt)*),
+        (#[:: $($mac:tt)::+ ! $(($($args:tt)*))*] $($tail:tt)*) -> $out:tt
+    ) => {
+        :: $($mac)::+ ! {
+            ($($($args)*)*),
+            then ($crate::macro_attr_impl! { @field_attrs_resume $fixed, $stack, $out, }),
+            $($tail)* $($comma)*
+        }
+    };
+
+    (
+        @field_attrs_walk $fixed:tt, $stack:tt, $comma:tt,
+        (#[$($attr:tt)*] $($tail:tt)*) -> ($($out:tt)*)
+    ) => {
+        $crate::macro_attr_impl! { @field_attrs_walk $fixed, $stack, $comma, ($($tail)*) -> ($($out)* #[$($attr)*]) }
+    };
+
+    (
+        @field_attrs_walk $fixed:tt, ($($stack:tt)*), $comma:tt,
+        ({ $($body:tt)* } $($tail:tt)*) -> $out:tt
+    ) => {
+        $crate::macro_attr_impl! { @field_attrs_walk $fixed, ((brace, ($($tail)*), $comma, $out) $($stack)*), (,), ($($body)*) -> () }
+    };
+
+    (
+        @field_attrs_walk $fixed:tt, ($($stack:tt)*), $comma:tt,
+        (($($body:tt)*) $($tail:tt)*) -> $out:tt
+    ) => {
+        $crate::macro_attr_impl! { @field_attrs_walk $fixed, ((paren, ($($tail)*), $comma, $out) $($stack)*), (,), ($($body)*) -> () }
+    };
+
+    (
+        @field_attrs_walk $fixed:tt, $stack:tt, (),
+        (,) -> $out:tt
+    ) => {
+        $crate::macro_attr_impl! { @field_attrs_walk $fixed, $stack, (), () -> $out }
+    };
+
+    (
+        @field_attrs_walk $fixed:tt, $stack:tt, $comma:tt,
+        ($next:tt $($tail:tt)*) -> ($($out:tt)*)
+    ) => {
+        $crate::macro_attr_impl! { @field_attrs_walk $fixed, $stack, $comma, ($($tail)*) -> ($($out)* $next) }
+    };
+
+    (
+        @field_attrs_walk $fixed:tt, ((brace, $tail:tt, $comma:tt, ($($out:tt)*)) $($stack:tt)*), $_comma:tt,
+        () -> ($($body:tt)*)
+    ) => {
+        $crate::macro_attr_impl! { @field_attrs_walk $fixed, ($($stack)*), $comma, $tail -> ($($out)* { $($body)* }) }
+    };
+
+    (
+        @field_attrs_walk $fixed:tt, ((paren, $tail:tt, $comma:tt, ($($out:tt)*)) $($stack:tt)*), $_comma:tt,
+        () -> ($($body:tt)*)
+    ) => {
+        $crate::macro_attr_impl! { @field_attrs_walk $fixed, ($($stack)*), $comma, $tail -> ($($out)* ( $($body)* )) }
+    };
+
+    (@field_attrs_walk $fixed:tt, (), $_comma:tt, () -> $it:tt) => {
+        $crate::macro_attr_impl! { @field_attrs_done $fixed, $it }
+    };
+
+    (@field_attrs_resume $fixed:tt, $stack:tt, $out:tt, $($tail:tt)*) => {
+        $crate::macro_attr_impl! { @field_attrs_walk $fixed, $stack, (), ($($tail)*) -> $out }
+    };
+
+    (@field_attrs_done ($non_derives:tt, $derives:tt), $it:tt) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            { $non_derives, $it, (), (), () },
+            $derives,
+            (),
+            ()
+        }
+    };
+
+    /*
+
+    # `@split_derive_attrs`
+
+    This is responsible for taking the list of derivation attributes and splitting them into "built-in" and "custom" groups.
+
+    A custom attribute is any which has a `!` after the name, like a macro.
+    */
+
+    (@split_derive_attrs
+        { ($(#[$($non_derives:tt)*],)*), ($($it:tt)*), $cfgs:tt, $trace:tt, $depth:tt },
+        ($(,)*), (), ($($user_drvs:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_helpers
+            ($(#[$($non_derives)*])*),
+            ($($it)*)
+        }
+
+        $crate::macro_attr_impl! {
+            @expand_user_drvs
+            ($($user_drvs)*),
+            { $cfgs, (), ($(#[$($non_derives)*])*), ($(#[$($non_derives)*])*), ($($it)*), $depth },
+            $trace
+        }
+
+        $crate::macro_attr_impl! { @check_dups ($), ($($it)*), ($($user_drvs)*) }
+    };
+
+    (@split_derive_attrs
+        { ($(#[$($non_derives:tt)*],)*), ($($it:tt)*), $cfgs:tt, $trace:tt, $depth:tt },
+        ($(,)*), ($($bi_drvs:tt)+), ($($user_drvs:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_helpers
+            (#[derive($($bi_drvs)+)] $(#[$($non_derives)*])*),
+            ($($it)*)
+        }
+
+        $crate::macro_attr_impl! {
+            @expand_user_drvs
+            ($($user_drvs)*),
+            { $cfgs, ($($bi_drvs)+), ($(#[$($non_derives)*])*), (#[derive($($bi_drvs)+)] $(#[$($non_derives)*])*), ($($it)*), $depth },
+            $trace
+        }
+
+        $crate::macro_attr_impl! { @check_dups ($), ($($it)*), ($($user_drvs)*) }
+    };
+
+    (@split_derive_attrs
+        $fixed:tt,
+        (,, $($tail:tt)*), $bi_drvs:tt, $user_drvs:tt
+    ) => {
+        $crate::macro_attr_impl! {
             @split_derive_attrs
             $fixed, ($($tail)*), $bi_drvs, $user_drvs
         }
@@ -814,7 +1889,7 @@ macro_rules! macro_attr_impl {
         $fixed:tt,
         (, $($tail:tt)*), $bi_drvs:tt, $user_drvs:tt
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_derive_attrs
             $fixed, ($($tail)*), $bi_drvs, $user_drvs
         }
@@ -822,30 +1897,147 @@ macro_rules! macro_attr_impl {
 
     /*
 
+    ## `cfg` Predicates
+
+    These were noted by `@split_attrs`, and are set aside for `@expand_user_drvs`.
+
+    */
+    (@split_derive_attrs
+        { $non_derives:tt, $it:tt, ($($cfgs:tt)*), $trace:tt, $depth:tt },
+        (@cfg $pred:tt, $($tail:tt)*), $bi_drvs:tt, $user_drvs:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            { $non_derives, $it, ($($cfgs)* $pred), $trace, $depth },
+            ($($tail)*), $bi_drvs, $user_drvs
+        }
+    };
+
+    /*
+
+    ## Tracing
+
+    If `@split_attrs` found a `#[macro_attr_trace(...)]` marker, the trace is at the front of the list.  It is set aside for `@trace_drvs`.
+
+    */
+    (@split_derive_attrs
+        { $non_derives:tt, $it:tt, $cfgs:tt, (), $depth:tt },
+        (@trace $decl:tt $records:tt, $($tail:tt)*), $bi_drvs:tt, $user_drvs:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            { $non_derives, $it, $cfgs, ($decl $records), $depth },
+            ($($tail)*), $bi_drvs, $user_drvs
+        }
+    };
+
+    /*
+
+    ## Nesting Depth
+
+    Items fed back through `macro_attr_nested!` are marked with how deeply they are nested.  This is set aside so that it can be passed on to context derivations.
+
+    */
+    (@split_derive_attrs
+        { $non_derives:tt, $it:tt, $cfgs:tt, $trace:tt, () },
+        (@depth $depth:tt, $($tail:tt)*), $bi_drvs:tt, $user_drvs:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            { $non_derives, $it, $cfgs, $trace, $depth },
+            ($($tail)*), $bi_drvs, $user_drvs
+        }
+    };
+
+    /*
+
     ## Custom Derivations
 
     Now we can handle the custom derivations.  There are two forms we care about: those *with* an argument, and those *without*.
 
     The *reason* we care is that, in order to simplify the derivation macros, we want to detect the argument-less case and generate an empty pair of parens.
 
+    The name of a derivation may be a path, including one starting with `::` or `$crate`.  Since `$crate` isn't matched by `ident`, the first segment is matched as a `tt`.  Each derivation is recorded as its (parenthesised) path and arguments.
+
+    */
+    /*
+
+    Runs of derivations named by a single identifier, like `Name!` or `Name!(args)`, are consumed eight at a time.
+
     */
     (@split_derive_attrs
         $fixed:tt,
-        ($new_user:ident ! ($($new_user_args:tt)*), $($tail:tt)*), $bi_drvs:tt, ($($user_drvs:tt)*)
+        (
+            $n0:ident ! $(($($a0:tt)*))*, $n1:ident ! $(($($a1:tt)*))*,
+            $n2:ident ! $(($($a2:tt)*))*, $n3:ident ! $(($($a3:tt)*))*,
+            $n4:ident ! $(($($a4:tt)*))*, $n5:ident ! $(($($a5:tt)*))*,
+            $n6:ident ! $(($($a6:tt)*))*, $n7:ident ! $(($($a7:tt)*))*,
+            $($tail:tt)*
+        ),
+        $bi_drvs:tt, ($($user_drvs:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            $fixed, ($($tail)*), $bi_drvs,
+            (
+                $($user_drvs)*
+                ($n0) ($($($a0)*)*),
+                ($n1) ($($($a1)*)*),
+                ($n2) ($($($a2)*)*),
+                ($n3) ($($($a3)*)*),
+                ($n4) ($($($a4)*)*),
+                ($n5) ($($($a5)*)*),
+                ($n6) ($($($a6)*)*),
+                ($n7) ($($($a7)*)*),
+            )
+        }
+    };
+
+    (@split_derive_attrs
+        $fixed:tt,
+        (:: $new_user:ident $(:: $new_user_path:ident)* ! ($($new_user_args:tt)*), $($tail:tt)*),
+        $bi_drvs:tt, ($($user_drvs:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            $fixed, ($($tail)*), $bi_drvs,
+            ($($user_drvs)* (:: $new_user $(:: $new_user_path)*) ($($new_user_args)*),)
+        }
+    };
+
+    (@split_derive_attrs
+        $fixed:tt,
+        (:: $new_user:ident $(:: $new_user_path:ident)* !, $($tail:tt)*),
+        $bi_drvs:tt, ($($user_drvs:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            $fixed, ($($tail)*), $bi_drvs,
+            ($($user_drvs)* (:: $new_user $(:: $new_user_path)*) (),)
+        }
+    };
+
+    (@split_derive_attrs
+        $fixed:tt,
+        ($new_user:tt $(:: $new_user_path:ident)* ! ($($new_user_args:tt)*), $($tail:tt)*),
+        $bi_drvs:tt, ($($user_drvs:tt)*)
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_derive_attrs
-            $fixed, ($($tail)*), $bi_drvs, ($($user_drvs)* $new_user($($new_user_args)*),)
+            $fixed, ($($tail)*), $bi_drvs,
+            ($($user_drvs)* ($new_user $(:: $new_user_path)*) ($($new_user_args)*),)
         }
     };
 
     (@split_derive_attrs
         $fixed:tt,
-        ($new_user:ident !, $($tail:tt)*), $bi_drvs:tt, ($($user_drvs:tt)*)
+        ($new_user:tt $(:: $new_user_path:ident)* !, $($tail:tt)*),
+        $bi_drvs:tt, ($($user_drvs:tt)*)
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_derive_attrs
-            $fixed, ($($tail)*), $bi_drvs, ($($user_drvs)* $new_user(),)
+            $fixed, ($($tail)*), $bi_drvs,
+            ($($user_drvs)* ($new_user $(:: $new_user_path)*) (),)
         }
     };
 
@@ -853,30 +2045,50 @@ macro_rules! macro_attr_impl {
 
     ## Hybrid Derivations
 
-    These are derivations that use regular macros *or* procedural macros, depending on the version of Rust in use.
+    These are derivations that use regular macros *or* procedural macros, depending on whether procedural macro support is enabled.  The path is pulled out first, so that we only have to deal with it in one place.
 
     */
     (@split_derive_attrs
         $fixed:tt,
-        ($new_drv:ident ~!, $($tail:tt)*), ($($bi_drvs:ident,)*), ($($user_drvs:tt)*)
+        (:: $new_drv:ident $(:: $new_drv_path:ident)* ~!, $($tail:tt)*), $bi_drvs:tt, $user_drvs:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            $fixed, (@hybrid (:: $new_drv $(:: $new_drv_path)*), $($tail)*), $bi_drvs, $user_drvs
+        }
+    };
+
+    (@split_derive_attrs
+        $fixed:tt,
+        ($new_drv:tt $(:: $new_drv_path:ident)* ~!, $($tail:tt)*), $bi_drvs:tt, $user_drvs:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            $fixed, (@hybrid ($new_drv $(:: $new_drv_path)*), $($tail)*), $bi_drvs, $user_drvs
+        }
+    };
+
+    (@split_derive_attrs
+        $fixed:tt,
+        (@hybrid ($($new_drv:tt)*), $($tail:tt)*), ($($bi_drvs:tt)*), ($($user_drvs:tt)*)
     ) => {
-        macro_attr_if_proc_macros! {
+        $crate::macro_attr_if_proc_macros! {
             proc_macros: {
-                macro_attr_impl! {
+                $crate::macro_attr_impl! {
                     @split_derive_attrs
                     $fixed,
                     ($($tail)*),
-                    ($($bi_drvs,)* $new_drv,),
+                    ($($bi_drvs)* $($new_drv)*,),
                     ($($user_drvs)*)
                 }
             }
             fallback: {
-                macro_attr_impl! {
+                $crate::macro_attr_impl! {
                     @split_derive_attrs
                     $fixed,
                     ($($tail)*),
-                    ($($bi_drvs,)*),
-                    ($($user_drvs)* $new_drv(),)
+                    ($($bi_drvs)*),
+                    ($($user_drvs)* ($($new_drv)*) (),)
                 }
             }
         }
@@ -884,19 +2096,101 @@ macro_rules! macro_attr_impl {
 
     /*
 
+    ## Context Derivations
+
+    These are derivations written as `Name@!`, which want to be told about the rest of the item's derivations and attributes.  They are recorded like any other custom derivation, except that the arguments are wrapped in braces; `@expand_user_drvs` uses this to tell them apart.
+
+    */
+    (@split_derive_attrs
+        $fixed:tt,
+        (:: $new_user:ident $(:: $new_user_path:ident)* @! ($($new_user_args:tt)*), $($tail:tt)*),
+        $bi_drvs:tt, ($($user_drvs:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            $fixed, ($($tail)*), $bi_drvs,
+            ($($user_drvs)* (:: $new_user $(:: $new_user_path)*) {($($new_user_args)*)},)
+        }
+    };
+
+    (@split_derive_attrs
+        $fixed:tt,
+        (:: $new_user:ident $(:: $new_user_path:ident)* @!, $($tail:tt)*),
+        $bi_drvs:tt, ($($user_drvs:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            $fixed, ($($tail)*), $bi_drvs,
+            ($($user_drvs)* (:: $new_user $(:: $new_user_path)*) {()},)
+        }
+    };
+
+    (@split_derive_attrs
+        $fixed:tt,
+        ($new_user:tt $(:: $new_user_path:ident)* @! ($($new_user_args:tt)*), $($tail:tt)*),
+        $bi_drvs:tt, ($($user_drvs:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            $fixed, ($($tail)*), $bi_drvs,
+            ($($user_drvs)* ($new_user $(:: $new_user_path)*) {($($new_user_args)*)},)
+        }
+    };
+
+    (@split_derive_attrs
+        $fixed:tt,
+        ($new_user:tt $(:: $new_user_path:ident)* @!, $($tail:tt)*),
+        $bi_drvs:tt, ($($user_drvs:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            $fixed, ($($tail)*), $bi_drvs,
+            ($($user_drvs)* ($new_user $(:: $new_user_path)*) {()},)
+        }
+    };
+
+    /*
+
     ## Non-Macro Derivations
 
-    All the rest.
+    All the rest.  As with plain attributes, runs of these are consumed eight at a time.
 
     */
     (@split_derive_attrs
         $fixed:tt,
-        ($drv:ident, $($tail:tt)*), ($($bi_drvs:ident,)*), $user_drvs:tt
+        (
+            $d0:ident, $d1:ident, $d2:ident, $d3:ident,
+            $d4:ident, $d5:ident, $d6:ident, $d7:ident,
+            $($tail:tt)*
+        ),
+        ($($bi_drvs:tt)*), $user_drvs:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            $fixed,
+            ($($tail)*), ($($bi_drvs)* $d0, $d1, $d2, $d3, $d4, $d5, $d6, $d7,), $user_drvs
+        }
+    };
+
+    (@split_derive_attrs
+        $fixed:tt,
+        ($drv:ident, $($tail:tt)*), ($($bi_drvs:tt)*), $user_drvs:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @split_derive_attrs
+            $fixed,
+            ($($tail)*), ($($bi_drvs)* $drv,), $user_drvs
+        }
+    };
+
+    (@split_derive_attrs
+        $fixed:tt,
+        ($(::)* $drv:ident $(:: $drv_path:ident)+, $($tail:tt)*), ($($bi_drvs:tt)*), $user_drvs:tt
     ) => {
-        macro_attr_impl! {
+        $crate::macro_attr_impl! {
             @split_derive_attrs
             $fixed,
-            ($($tail)*), ($($bi_drvs,)* $drv,), $user_drvs
+            ($($tail)*), ($($bi_drvs)* $drv $(:: $drv_path)+,), $user_drvs
         }
     };
 
@@ -904,31 +2198,758 @@ macro_rules! macro_attr_impl {
 
     # `@expand_user_drvs`
 
-    Finally, we have a recursive rule for expanding user derivations.  This is basically just using the derivation name as a macro identifier.
+    Finally, we expand the user derivations.  This is basically just using the derivation's path as the path of a macro.  Each invocation is gated on the item's `cfg` predicates, if it has any.
 
-    This *has* to be recursive because we need to expand two independent repetition sequences simultaneously, and this causes `macro_rules!` to throw a wobbly.  Don't want that.  So, recursive it is.
+    These are all expanded side-by-side, rather than recursively, so that the number of derivations doesn't count against the recursion limit.  To make this work, the item (and the predicates) have to be passed around as a single token tree; otherwise we'd need to expand two independent repetition sequences simultaneously, and this causes `macro_rules!` to throw a wobbly.
 
-    */
-    (@expand_user_drvs
-        (), ($($it:tt)*)
-    ) => {};
+    The `$fixed` capture holds the predicates, the built-in derivations, the other attributes, the attributes the item was emitted with, the item, and its nesting depth.
 
+    */
     (@expand_user_drvs
-        ($user_drv:ident $arg:tt, $($tail:tt)*), ($($it:tt)*)
+        ($($user_drv:tt ($($arg:tt)*),)*), $fixed:tt, $trace:tt
     ) => {
-        $user_drv! { $arg $($it)* }
-        macro_attr_impl! {
-            @expand_user_drvs
-            ($($tail)*), ($($it)*)
+        $crate::macro_attr_impl! {
+            @expand_user_drvs_ctx
+            ($($user_drv ($($arg)*),)*), (), $fixed, $trace
         }
     };
 
     /*
 
-    # Miscellaneous Rules
+    If any derivation wants the context, we have to build it first.  This means going through the derivations one at a time to turn them back into `path!(args)` form.
 
     */
-    (@as_item $($i:item)*) => {$($i)*};
+    (@expand_user_drvs
+        $user_drvs:tt, $fixed:tt, $trace:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @drv_context
+            $user_drvs, (), ($user_drvs, $fixed, $trace)
+        }
+    };
+
+    (@drv_context
+        (($($user_drv:tt)*) ($($arg:tt)*), $($tail:tt)*), ($($drvs:tt)*), $fixed:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @drv_context
+            ($($tail)*), ($($drvs)* $($user_drv)*!($($arg)*),), $fixed
+        }
+    };
+
+    (@drv_context
+        (($($user_drv:tt)*) {($($arg:tt)*)}, $($tail:tt)*), ($($drvs:tt)*), $fixed:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @drv_context
+            ($($tail)*), ($($drvs)* $($user_drv)*@!($($arg)*),), $fixed
+        }
+    };
+
+    (@drv_context
+        (), ($($drvs:tt)*),
+        ($user_drvs:tt, { $cfgs:tt, ($($bi_drvs:tt)*), ($($attrs:tt)*), $out:tt, $it:tt, ($($depth:tt)*) }, $trace:tt)
+    ) => {
+        $crate::macro_attr_impl! {
+            @drv_context_depth
+            $user_drvs,
+            (derive($($bi_drvs)*), macros($($drvs)*), attrs($($attrs)*)),
+            { $cfgs, ($($bi_drvs)*), ($($attrs)*), $out, $it, ($($depth)*) },
+            $trace
+        }
+    };
+
+    /*
+
+    The depth is only added to the context for nested items, so that derivations which don't care about nesting see the same context they always have.
+
+    */
+    (@drv_context_depth
+        $user_drvs:tt, ($($ctx:tt)*), { $cfgs:tt, $bi_drvs:tt, $attrs:tt, $out:tt, $it:tt, () }, $trace:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @expand_user_drvs_ctx
+            $user_drvs, { $($ctx)* }, { $cfgs, $bi_drvs, $attrs, $out, $it, () }, $trace
+        }
+    };
+
+    (@drv_context_depth
+        $user_drvs:tt, ($($ctx:tt)*), { $cfgs:tt, $bi_drvs:tt, $attrs:tt, $out:tt, $it:tt, ($($depth:tt)+) }, $trace:tt
+    ) => {
+        $crate::macro_attr_impl! {
+            @expand_user_drvs_ctx
+            $user_drvs, { $($ctx)*, depth($($depth)+) }, { $cfgs, $bi_drvs, $attrs, $out, $it, ($($depth)+) }, $trace
+        }
+    };
+
+    (@expand_user_drvs_ctx
+        ($($user_drv:tt $arg:tt,)*), $ctx:tt, { $cfgs:tt, $_bi_drvs:tt, $_attrs:tt, $out:tt, $it:tt, $_depth:tt }, $trace:tt
+    ) => {
+        $(
+            $crate::macro_attr_impl! {
+                @expand_user_drv
+                $user_drv $arg, $ctx, $cfgs, $it
+            }
+        )*
+
+        $crate::macro_attr_impl! {
+            @trace_drvs
+            $trace, $ctx, $cfgs, $out, $it, ($($user_drv $arg,)*)
+        }
+    };
+
+    (@expand_user_drv
+        ($($user_drv:tt)*) ($($arg:tt)*), $_ctx:tt, ($($cfg:tt)*), ($($it:tt)*)
+    ) => {
+        $(#[cfg $cfg])*
+        $($user_drv)*! { ($($arg)*) $($it)* }
+    };
+
+    (@expand_user_drv
+        ($($user_drv:tt)*) {$arg:tt}, $ctx:tt, ($($cfg:tt)*), ($($it:tt)*)
+    ) => {
+        $(#[cfg $cfg])*
+        $($user_drv)*! { $arg $ctx $($it)* }
+    };
+
+    /*
+
+    # `@check_dups`
+
+    Rejects any derivation which appears more than once with the same arguments, since that would otherwise show up as a confusing error in whatever the derivation expands to.  `Name@!(args)` is treated as the same derivation as `Name!(args)`, since they invoke the same macro.
+
+    `macro_rules!` can't compare two arbitrary sequences of tokens, but a macro *can* be defined with them as literal patterns.  So, once `@split_derive_attrs` has collected all of an item's derivations, we define a throwaway macro with two rules for each one, in order: one which matches the derivation itself, and one which matches any *other* derivation that is the same.  Each derivation is then checked with a single invocation; a duplicate will match the second rule of the first derivation it repeats.  Arguments that were forwarded from another macro as anything other than `tt`s (say, an `$e:expr`) can't be compared to anything, so such derivations fall through to a final rule that accepts them unchecked.  The `$d` capture is a literal `$`, as in `@bundle_def`.
+
+    Defining a macro isn't allowed inside an `impl` or `trait`, so only `struct`, `enum` and `union` items are checked; these are the only items which can't appear there.  There's also nothing to check for items with fewer than two derivations.
+
+    */
+    (@check_dups $_d:tt, $_it:tt, ()) => {};
+
+    (@check_dups $_d:tt, $_it:tt, ($_drv:tt $_arg:tt,)) => {};
+
+    (@check_dups $d:tt, ($_vis:vis struct $($_it:tt)*), $user_drvs:tt) => {
+        $crate::macro_attr_impl! { @dup_marks $d, $user_drvs, (), () }
+    };
+
+    (@check_dups $d:tt, ($_vis:vis enum $($_it:tt)*), $user_drvs:tt) => {
+        $crate::macro_attr_impl! { @dup_marks $d, $user_drvs, (), () }
+    };
+
+    (@check_dups $d:tt, ($_vis:vis union $($_it:tt)*), $user_drvs:tt) => {
+        $crate::macro_attr_impl! { @dup_marks $d, $user_drvs, (), () }
+    };
+
+    (@check_dups $_d:tt, $_it:tt, $_user_drvs:tt) => {};
+
+    /*
+
+    ## `@dup_marks`
+
+    Gives each derivation a distinct mark, so that it can tell its own rule apart from those of any earlier duplicates, and takes the braces off the arguments of context derivations.  Regular derivations are done eight at a time; the mark is a count of the steps taken so far, plus the position in the run.
+
+    */
+    (@dup_marks $d:tt,
+        (
+            $n0:tt ($($a0:tt)*), $n1:tt ($($a1:tt)*), $n2:tt ($($a2:tt)*), $n3:tt ($($a3:tt)*),
+            $n4:tt ($($a4:tt)*), $n5:tt ($($a5:tt)*), $n6:tt ($($a6:tt)*), $n7:tt ($($a7:tt)*),
+            $($tail:tt)*
+        ),
+        ($($count:tt)*), ($($out:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @dup_marks $d, ($($tail)*), ($($count)* +),
+            (
+                $($out)*
+                $n0 ($($a0)*) [$($count)* 0], $n1 ($($a1)*) [$($count)* 1],
+                $n2 ($($a2)*) [$($count)* 2], $n3 ($($a3)*) [$($count)* 3],
+                $n4 ($($a4)*) [$($count)* 4], $n5 ($($a5)*) [$($count)* 5],
+                $n6 ($($a6)*) [$($count)* 6], $n7 ($($a7)*) [$($count)* 7],
+            )
+        }
+    };
+
+    (@dup_marks $d:tt, ($user_drv:tt {$arg:tt}, $($tail:tt)*), ($($count:tt)*), ($($out:tt)*)) => {
+        $crate::macro_attr_impl! {
+            @dup_marks $d, ($($tail)*), ($($count)* +), ($($out)* $user_drv $arg [$($count)*],)
+        }
+    };
+
+    (@dup_marks $d:tt, ($user_drv:tt $arg:tt, $($tail:tt)*), ($($count:tt)*), ($($out:tt)*)) => {
+        $crate::macro_attr_impl! {
+            @dup_marks $d, ($($tail)*), ($($count)* +), ($($out)* $user_drv $arg [$($count)*],)
+        }
+    };
+
+    (@dup_marks ($d:tt), (), $_count:tt, ($($user_drv:tt $arg:tt $mark:tt,)*)) => {
+        macro_rules! __macro_attr_check_dup {
+            $(
+                ($user_drv $arg $mark) => {};
+                ($user_drv $arg $d _mark:tt) => {
+                    $crate::macro_attr_impl! { @dup_error $user_drv $arg }
+                };
+            )*
+            ($d($d _tt:tt)*) => {};
+        }
+
+        $(
+            __macro_attr_check_dup! { $user_drv $arg $mark }
+        )*
+    };
+
+    (@dup_error ($($user_drv:tt)*) $arg:tt) => {
+        compile_error!(concat!(
+            "duplicate derivation `", stringify!($($user_drv)*! $arg),
+            "`; a derivation may only be given once with the same arguments"
+        ));
+    };
+
+    /*
+
+    # `@nested`
+
+    Used by `macro_attr_nested!` to find out how deeply nested the caller is, either from a derivation context or from the callback given to a macro attribute.  The latter may be buried in a continuation stack, in which case any groups are searched as well.  In the callback, the depth is at the front of the list of derivations (after the trace, if there is one).  Each level of nesting is represented by a single `+`.
+
+    */
+    (@nested { derive $_bi_drvs:tt, macros $_drvs:tt, attrs $_attrs:tt }, $it:tt) => {
+        $crate::macro_attr_impl! { @nested_emit (), $it }
+    };
+
+    (@nested { derive $_bi_drvs:tt, macros $_drvs:tt, attrs $_attrs:tt, depth $depth:tt }, $it:tt) => {
+        $crate::macro_attr_impl! { @nested_emit $depth, $it }
+    };
+
+    (@nested ($_krate:tt :: macro_attr_impl! { @split_attrs_resume $_non_derives:tt, $derives:tt, }), $it:tt) => {
+        $crate::macro_attr_impl! { @nested_depth $derives, $it }
+    };
+
+    (@nested ($($cb:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @nested_find_cb ($($cb)*), $it }
+    };
+
+    (@nested_find_cb ($_krate:tt :: macro_attr_impl! { @split_attrs_resume $_non_derives:tt, $derives:tt, } $($_tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @nested_depth $derives, $it }
+    };
+
+    (@nested_find_cb (($($inner:tt)*) $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @nested_find_cb ($($inner)* $($tail)*), $it }
+    };
+
+    (@nested_find_cb ({$($inner:tt)*} $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @nested_find_cb ($($inner)* $($tail)*), $it }
+    };
+
+    (@nested_find_cb ($_other:tt $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @nested_find_cb ($($tail)*), $it }
+    };
+
+    (@nested_find_cb (), ($($it:tt)*)) => {
+        compile_error!(concat!(
+            "macro_attr_nested!: expected a derivation context or the callback given to a macro attribute, for `",
+            stringify!($($it)*), "`"
+        ));
+    };
+
+    (@nested_depth (@depth $depth:tt, $($_derives:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @nested_emit $depth, $it }
+    };
+
+    (@nested_depth (@trace $_decl:tt $_records:tt, @depth $depth:tt, $($_derives:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @nested_emit $depth, $it }
+    };
+
+    (@nested_depth $_derives:tt, $it:tt) => {
+        $crate::macro_attr_impl! { @nested_emit (), $it }
+    };
+
+    (@nested_emit (+ + + +), ($($it:tt)*)) => {
+        compile_error!(concat!(
+            "macro_attr_nested!: items nested more than 4 levels deep; is a derivation re-entering itself? At `",
+            stringify!($($it)*), "`"
+        ));
+    };
+
+    (@nested_emit ($($depth:tt)*), ($($it:tt)*)) => {
+        $crate::macro_attr_impl! { #[macro_attr_depth(+ $($depth)*)] $($it)* }
+    };
+
+    /*
+
+    # `@inherit`
+
+    Used by `macro_attr_inherit!` to pick the lint attributes out of a derivation context.  A `#[deprecated]` item can't make its derivations deprecated as well (the compiler rejects `#[deprecated]` on an `impl`), so it becomes `#[allow(deprecated)]` instead.
+
+    */
+    (@inherit { derive $_bi_drvs:tt, macros $_drvs:tt, attrs($($attrs:tt)*) $(, depth $_depth:tt)* }, $items:tt) => {
+        $crate::macro_attr_impl! { @inherit_lints ($($attrs)*), (), $items }
+    };
+
+    (@inherit $ctx:tt, ($($items:tt)*)) => {
+        compile_error!(concat!(
+            "macro_attr_inherit!: expected a derivation context, for `",
+            stringify!($($items)*), "`"
+        ));
+    };
+
+    (@inherit_lints (#[allow($($lints:tt)*)] $($tail:tt)*), ($($out:tt)*), $items:tt) => {
+        $crate::macro_attr_impl! { @inherit_lints ($($tail)*), ($($out)* #[allow($($lints)*)]), $items }
+    };
+
+    (@inherit_lints (#[deprecated $($_args:tt)*] $($tail:tt)*), ($($out:tt)*), $items:tt) => {
+        $crate::macro_attr_impl! { @inherit_lints ($($tail)*), ($($out)* #[allow(deprecated)]), $items }
+    };
+
+    (@inherit_lints (#[$($_attr:tt)*] $($tail:tt)*), $out:tt, $items:tt) => {
+        $crate::macro_attr_impl! { @inherit_lints ($($tail)*), $out, $items }
+    };
+
+    (@inherit_lints (), $lints:tt, ($($item:item)*)) => {
+        $(
+            $crate::macro_attr_impl! { @inherit_item $lints, $item }
+        )*
+    };
+
+    (@inherit_item ($($lints:tt)*), $item:item) => {
+        $($lints)* $item
+    };
+
+    /*
+
+    # `@trace_drvs`
+
+    Emits the trace, if one was asked for.  Each derivation's record is added one at a time, for the same reason `@expand_user_drvs` can't expand them with the item in a single rule.  The constant is gated on the item's `cfg` predicates, just like the derivations.
+
+    */
+    (@trace_drvs (), $_ctx:tt, $_cfgs:tt, $_out:tt, $_it:tt, $_user_drvs:tt) => {};
+
+    (@trace_drvs
+        ($decl:tt ($($records:tt)*)), $ctx:tt, $cfgs:tt, $out:tt, ($($it:tt)*),
+        (($($user_drv:tt)*) ($($arg:tt)*), $($tail:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @trace_drvs
+            ($decl (
+                $($records)*
+                "derivation: ", stringify!($($user_drv)*!($($arg)*)), "\n",
+                "input: ", stringify!(($($arg)*) $($it)*), "\n",
+            )),
+            $ctx, $cfgs, $out, ($($it)*), ($($tail)*)
+        }
+    };
+
+    (@trace_drvs
+        ($decl:tt ($($records:tt)*)), $ctx:tt, $cfgs:tt, $out:tt, ($($it:tt)*),
+        (($($user_drv:tt)*) {($($arg:tt)*)}, $($tail:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @trace_drvs
+            ($decl (
+                $($records)*
+                "derivation: ", stringify!($($user_drv)*@!($($arg)*)), "\n",
+                "input: ", stringify!(($($arg)*) $ctx $($it)*), "\n",
+            )),
+            $ctx, $cfgs, $out, ($($it)*), ($($tail)*)
+        }
+    };
+
+    (@trace_drvs
+        (($vis:vis $name:ident) ($($records:tt)*)), $_ctx:tt, ($($cfg:tt)*), ($($out:tt)*), ($($it:tt)*),
+        ()
+    ) => {
+        $(#[cfg $cfg])*
+        $vis const $name: &'static str = concat!(
+            $($records)*
+            "output: ", stringify!($($out)* $($it)*), "\n",
+        );
+    };
+
+    /*
+
+    # `@bundle_def`
+
+    Defines a derivation bundle.  The `$d` capture is a literal `$`, which is needed to write the captures of the new macro.
+
+    Used as a macro attribute, the bundle just adds its members to the item as a new `#[derive(...)]` attribute and lets `@split_attrs` deal with them.  Used as a derivation, it is too late to add built-in derivations to the item, so `@bundle_drvs` invokes the members directly, one at a time.
+
+    */
+    (
+        @bundle_def ($d:tt), ($($attrs:tt)*), $name:ident, ($($members:tt)*)
+    ) => {
+        $($attrs)*
+        macro_rules! $name {
+            ((), then $d resume:tt, $d ($d it:tt)*) => {
+                $crate::macro_attr_callback! { $d resume, #[derive($($members)*)] $d ($d it)* }
+            };
+
+            (() $d ($d it:tt)*) => {
+                $crate::macro_attr_impl! { @bundle_drvs $name, ($($members)*,), ($d ($d it)*) }
+            };
+        }
+    };
+
+    (@bundle_drvs $_name:ident, ($(,)*), $_it:tt) => {};
+
+    (@bundle_drvs $name:ident, (, $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @bundle_drvs $name, ($($tail)*), $it }
+    };
+
+    (@bundle_drvs $name:ident, (:: $($drv:tt)::+ ! ($($args:tt)*), $($tail:tt)*), ($($it:tt)*)) => {
+        :: $($drv)::+ ! { ($($args)*) $($it)* }
+        $crate::macro_attr_impl! { @bundle_drvs $name, ($($tail)*), ($($it)*) }
+    };
+
+    (@bundle_drvs $name:ident, (:: $($drv:tt)::+ !, $($tail:tt)*), ($($it:tt)*)) => {
+        :: $($drv)::+ ! { () $($it)* }
+        $crate::macro_attr_impl! { @bundle_drvs $name, ($($tail)*), ($($it)*) }
+    };
+
+    (@bundle_drvs $name:ident, ($($drv:tt)::+ ! ($($args:tt)*), $($tail:tt)*), ($($it:tt)*)) => {
+        $($drv)::+ ! { ($($args)*) $($it)* }
+        $crate::macro_attr_impl! { @bundle_drvs $name, ($($tail)*), ($($it)*) }
+    };
+
+    (@bundle_drvs $name:ident, ($($drv:tt)::+ !, $($tail:tt)*), ($($it:tt)*)) => {
+        $($drv)::+ ! { () $($it)* }
+        $crate::macro_attr_impl! { @bundle_drvs $name, ($($tail)*), ($($it)*) }
+    };
+
+    (@bundle_drvs $name:ident, ($($drv:tt)::+ ~!, $($tail:tt)*), ($($it:tt)*)) => {
+        $crate::macro_attr_if_proc_macros! {
+            proc_macros: {
+                $crate::macro_attr_impl! { @bundle_builtin $name, ($($drv)::+) }
+            }
+            fallback: {
+                $($drv)::+ ! { () $($it)* }
+            }
+        }
+        $crate::macro_attr_impl! { @bundle_drvs $name, ($($tail)*), ($($it)*) }
+    };
+
+    (@bundle_drvs $name:ident, (:: $($drv:tt)::+ @! $(($($_args:tt)*))*, $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @bundle_attr_only $name, (:: $($drv)::+), "the context derivation" }
+        $crate::macro_attr_impl! { @bundle_drvs $name, ($($tail)*), $it }
+    };
+
+    (@bundle_drvs $name:ident, ($($drv:tt)::+ @! $(($($_args:tt)*))*, $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @bundle_attr_only $name, ($($drv)::+), "the context derivation" }
+        $crate::macro_attr_impl! { @bundle_drvs $name, ($($tail)*), $it }
+    };
+
+    (@bundle_drvs $name:ident, (:: $($drv:tt)::+, $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @bundle_builtin $name, (:: $($drv)::+) }
+        $crate::macro_attr_impl! { @bundle_drvs $name, ($($tail)*), $it }
+    };
+
+    (@bundle_drvs $name:ident, ($($drv:tt)::+, $($tail:tt)*), $it:tt) => {
+        $crate::macro_attr_impl! { @bundle_builtin $name, ($($drv)::+) }
+        $crate::macro_attr_impl! { @bundle_drvs $name, ($($tail)*), $it }
+    };
+
+    (@bundle_builtin $name:ident, $drv:tt) => {
+        $crate::macro_attr_impl! { @bundle_attr_only $name, $drv, "the built-in derivation" }
+    };
+
+    (@bundle_attr_only $name:ident, ($($drv:tt)*), $what:expr) => {
+        compile_error!(concat!(
+            "derivation bundle `", stringify!($name), "` contains ", $what, " `",
+            stringify!($($drv)*), "`, so it must be used as `#[", stringify!($name),
+            "!]` rather than `#[derive(", stringify!($name), "!)]`"
+        ));
+    };
+
+    /*
+
+    # `@strip_helpers`
+
+    Removes any `#[macro_attr(...)]` helper attributes from the fields and variants of the item before it is emitted.  Derivations are given the item *before* this happens, so they can still see the helpers.
+
+    Only `struct`, `enum` and `union` items can have fields or variants.  Modules, `impl` blocks and traits have their contents processed instead, and anything else is passed straight through.
+
+    */
+    (@strip_helpers $attrs:tt, ($vis:vis struct $name:ident $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_head $attrs, ($vis struct $name), ($($tail)*) }
+    };
+
+    (@strip_helpers $attrs:tt, ($vis:vis union $name:ident $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_head $attrs, ($vis union $name), ($($tail)*) }
+    };
+
+    (@strip_helpers $attrs:tt, ($vis:vis enum $name:ident $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_head_enum $attrs, ($vis enum $name), ($($tail)*) }
+    };
+
+    (@strip_helpers ($($attrs:tt)*), ($vis:vis mod $name:ident { $($body:tt)* })) => {
+        $crate::macro_attr_impl! { @strip_mod ($($attrs)* $vis mod $name), (), ($($body)*) }
+    };
+
+    (@strip_helpers ($($attrs:tt)*), ($vis:vis impl $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_assoc ($($attrs)* $vis impl), ($($tail)*) }
+    };
+
+    (@strip_helpers ($($attrs:tt)*), ($vis:vis unsafe impl $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_assoc ($($attrs)* $vis unsafe impl), ($($tail)*) }
+    };
+
+    (@strip_helpers ($($attrs:tt)*), ($vis:vis trait $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_assoc ($($attrs)* $vis trait), ($($tail)*) }
+    };
+
+    (@strip_helpers ($($attrs:tt)*), ($vis:vis unsafe trait $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_assoc ($($attrs)* $vis unsafe trait), ($($tail)*) }
+    };
+
+    (@strip_helpers ($($attrs:tt)*), ($($it:tt)*)) => {
+        $crate::macro_attr_impl! { @as_item $($attrs)* $($it)* }
+    };
+
+    /*
+
+    ## `@strip_assoc`
+
+    Skips over the header of an `impl` or `trait` to find its body, which is always the last token tree.  The associated items inside are then processed in the same way as the contents of a module.
+
+    */
+    (@strip_assoc $head:tt, ({ $($body:tt)* })) => {
+        $crate::macro_attr_impl! { @strip_mod $head, (), ($($body)*) }
+    };
+
+    (@strip_assoc ($($head:tt)*), ($next:tt $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_assoc ($($head)* $next), ($($tail)*) }
+    };
+
+    /*
+
+    ## `@strip_mod`
+
+    The contents of an inline module (or an `impl` or `trait` body) are themselves processed by `macro_attr!`.  Inner attributes have to stay at the start of the body, so they are pulled out first.
+
+    */
+    (@strip_mod $head:tt, ($($inner:tt)*), (#![$($attr:tt)*] $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_mod $head, ($($inner)* #![$($attr)*]), ($($tail)*) }
+    };
+
+    (@strip_mod ($($head:tt)*), ($($inner:tt)*), $body:tt) => {
+        $crate::macro_attr_impl! {
+            @as_item
+            $($head)* {
+                $($inner)*
+                $crate::macro_attr_impl! { @items $body }
+            }
+        }
+    };
+
+    /*
+
+    ## `@strip_head`
+
+    Skips over the generics and `where` clause of a `struct` or `union`, looking for the body.  Tuple bodies are distinguished from parenthesised bounds like `Fn(u8)` by what follows them.
+
+    */
+    (@strip_head $attrs:tt, $head:tt, ({ $($body:tt)* })) => {
+        $crate::macro_attr_impl! {
+            @strip_named_fields
+            ($crate::macro_attr_impl! { @strip_emit $attrs, $head, {}, (), }),
+            ($($body)*,) -> ()
+        }
+    };
+
+    (@strip_head $attrs:tt, $head:tt, (($($body:tt)*); )) => {
+        $crate::macro_attr_impl! {
+            @strip_tuple_fields
+            ($crate::macro_attr_impl! { @strip_emit $attrs, $head, (), (;), }),
+            ($($body)*,) -> ()
+        }
+    };
+
+    (@strip_head $attrs:tt, $head:tt, (($($body:tt)*) where $($tail:tt)*)) => {
+        $crate::macro_attr_impl! {
+            @strip_tuple_fields
+            ($crate::macro_attr_impl! { @strip_emit $attrs, $head, (), (where $($tail)*), }),
+            ($($body)*,) -> ()
+        }
+    };
+
+    (@strip_head ($($attrs:tt)*), ($($head:tt)*), ()) => {
+        $crate::macro_attr_impl! { @as_item $($attrs)* $($head)* }
+    };
+
+    (@strip_head $attrs:tt, ($($head:tt)*), ($next:tt $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_head $attrs, ($($head)* $next), ($($tail)*) }
+    };
+
+    (@strip_head_enum $attrs:tt, $head:tt, ({ $($body:tt)* })) => {
+        $crate::macro_attr_impl! {
+            @strip_variants
+            ($crate::macro_attr_impl! { @strip_emit $attrs, $head, {}, (), }),
+            ($($body)*,) -> ()
+        }
+    };
+
+    (@strip_head_enum $attrs:tt, ($($head:tt)*), ($next:tt $($tail:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_head_enum $attrs, ($($head)* $next), ($($tail)*) }
+    };
+
+    (
+        @strip_emit ($($attrs:tt)*), ($($head:tt)*), {}, ($($tail:tt)*),
+        ($($body:tt)*)
+    ) => {
+        $crate::macro_attr_impl! { @as_item $($attrs)* $($head)* { $($body)* } $($tail)* }
+    };
+
+    (
+        @strip_emit ($($attrs:tt)*), ($($head:tt)*), (), ($($tail:tt)*),
+        ($($body:tt)*)
+    ) => {
+        $crate::macro_attr_impl! { @as_item $($attrs)* $($head)* ( $($body)* ) $($tail)* }
+    };
+
+    /*
+
+    ## `@strip_named_fields`, `@strip_tuple_fields`
+
+    These walk a field list one attribute or field at a time, dropping helpers.  The result is passed to the callback as a single group.
+
+    */
+    (@strip_named_fields $cb:tt, ($(,)*) -> $out:tt) => {
+        $crate::macro_attr_callback! { $cb, $out }
+    };
+
+    (@strip_named_fields $cb:tt, (#[macro_attr $($_helper:tt)*] $($tail:tt)*) -> $out:tt) => {
+        $crate::macro_attr_impl! { @strip_named_fields $cb, ($($tail)*) -> $out }
+    };
+
+    (@strip_named_fields $cb:tt, (#[$($attr:tt)*] $($tail:tt)*) -> ($($out:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_named_fields $cb, ($($tail)*) -> ($($out)* #[$($attr)*]) }
+    };
+
+    (
+        @strip_named_fields $cb:tt,
+        ($vis:vis $name:ident: $ty:ty, $($tail:tt)*) -> ($($out:tt)*)
+    ) => {
+        $crate::macro_attr_impl! { @strip_named_fields $cb, ($($tail)*) -> ($($out)* $vis $name: $ty,) }
+    };
+
+    (@strip_tuple_fields $cb:tt, ($(,)*) -> $out:tt) => {
+        $crate::macro_attr_callback! { $cb, $out }
+    };
+
+    (@strip_tuple_fields $cb:tt, (#[macro_attr $($_helper:tt)*] $($tail:tt)*) -> $out:tt) => {
+        $crate::macro_attr_impl! { @strip_tuple_fields $cb, ($($tail)*) -> $out }
+    };
+
+    (@strip_tuple_fields $cb:tt, (#[$($attr:tt)*] $($tail:tt)*) -> ($($out:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_tuple_fields $cb, ($($tail)*) -> ($($out)* #[$($attr)*]) }
+    };
+
+    (@strip_tuple_fields $cb:tt, ($vis:vis $ty:ty, $($tail:tt)*) -> ($($out:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_tuple_fields $cb, ($($tail)*) -> ($($out)* $vis $ty,) }
+    };
+
+    /*
+
+    ## `@strip_variants`
+
+    As above, but for the variants of an `enum`.  The fields of tuple and struct variants are stripped by "calling" the field rules, which then resume here via `@strip_variant_body`.
+
+    */
+    (@strip_variants $cb:tt, ($(,)*) -> $out:tt) => {
+        $crate::macro_attr_callback! { $cb, $out }
+    };
+
+    (@strip_variants $cb:tt, (#[macro_attr $($_helper:tt)*] $($tail:tt)*) -> $out:tt) => {
+        $crate::macro_attr_impl! { @strip_variants $cb, ($($tail)*) -> $out }
+    };
+
+    (@strip_variants $cb:tt, (#[$($attr:tt)*] $($tail:tt)*) -> ($($out:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_variants $cb, ($($tail)*) -> ($($out)* #[$($attr)*]) }
+    };
+
+    (
+        @strip_variants $cb:tt,
+        ($name:ident { $($body:tt)* }, $($tail:tt)*) -> ($($out:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_named_fields
+            ($crate::macro_attr_impl! { @strip_variant_body $cb, {}, ($($tail)*) -> ($($out)* $name), }),
+            ($($body)*,) -> ()
+        }
+    };
+
+    (
+        @strip_variants $cb:tt,
+        ($name:ident ($($body:tt)*), $($tail:tt)*) -> ($($out:tt)*)
+    ) => {
+        $crate::macro_attr_impl! {
+            @strip_tuple_fields
+            ($crate::macro_attr_impl! { @strip_variant_body $cb, (), ($($tail)*) -> ($($out)* $name), }),
+            ($($body)*,) -> ()
+        }
+    };
+
+    (
+        @strip_variants $cb:tt,
+        ($name:ident = $disc:expr, $($tail:tt)*) -> ($($out:tt)*)
+    ) => {
+        $crate::macro_attr_impl! { @strip_variants $cb, ($($tail)*) -> ($($out)* $name = $disc,) }
+    };
+
+    (@strip_variants $cb:tt, ($name:ident, $($tail:tt)*) -> ($($out:tt)*)) => {
+        $crate::macro_attr_impl! { @strip_variants $cb, ($($tail)*) -> ($($out)* $name,) }
+    };
+
+    (
+        @strip_variant_body $cb:tt, {}, $tail:tt -> ($($out:tt)*),
+        ($($body:tt)*)
+    ) => {
+        $crate::macro_attr_impl! { @strip_variants $cb, $tail -> ($($out)* { $($body)* },) }
+    };
+
+    (
+        @strip_variant_body $cb:tt, (), $tail:tt -> ($($out:tt)*),
+        ($($body:tt)*)
+    ) => {
+        $crate::macro_attr_impl! { @strip_variants $cb, $tail -> ($($out)* ( $($body)* ),) }
+    };
+
+    /*
+
+    # Miscellaneous Rules
+
+    */
+    (@as_item $($i:item)*) => {$($i)*};
+
+    /*
+
+    # Errors
+
+    If nothing else matched, the input (or the output of a macro attribute) is not something `macro_attr!` knows how to process.  Only the item itself is shown, never the internal state around it.  Any other internal rule which fails to match is a bug, rather than something the user wrote.
+
+    */
+    (@unsupported_item ($($it:tt)*)) => {
+        compile_error!(concat!(
+            "macro_attr!: expected a supported item, found `", stringify!($($it)*), "`"
+        ));
+    };
+
+    (@unsupported_output ($($it:tt)*)) => {
+        compile_error!(concat!(
+            "macro_attr!: a macro attribute produced something that is not a supported item: `",
+            stringify!($($it)*), "`"
+        ));
+    };
+
+    (@split_attrs_resume $_non_derives:tt, $_derives:tt, $($it:tt)*) => {
+        $crate::macro_attr_impl! { @unsupported_output ($($it)*) }
+    };
+
+    (@ $rule:ident $($_state:tt)*) => {
+        compile_error!(concat!(
+            "macro_attr!: internal error: no rule matched in `@", stringify!($rule), "`"
+        ));
+    };
+
+    ($($it:tt)*) => {
+        $crate::macro_attr_impl! { @unsupported_item ($($it)*) }
+    };
 }
 
 /**
@@ -937,37 +2958,327 @@ This macro invokes a "callback" macro, merging arguments together.
 It takes an arbitrary macro call `(name!(args...))`, plus some sequence of `new_args...`, and expands `name!(args... new_args...)`.
 
 Importantly, it works irrespective of the kind of grouping syntax used for the macro arguments, simplifying macros which need to *capture* callbacks.
+
+The callback's name may be a path, such as `$crate::name!(...)` or `::some_crate::name!(...)`.
+
+# Continuation Stacks
+
+Instead of a single callback, a comma-separated list of callbacks `(first!(...), second!(...), ...)` may be given.  In this case, `first!` is invoked with `then (second!(...), ...),` inserted between its own arguments and the new arguments.  This is the same form macro attributes are invoked with, so a macro attribute can run several other macro attributes in sequence by pushing them onto the front of its own continuation:
+
+```rust
+# #[macro_use] extern crate macro_attr;
+macro_rules! rename_to {
+    (($name:ident), then $cb:tt, struct $_old:ident $($tail:tt)*) => {
+        macro_attr_callback! { $cb, struct $name $($tail)* }
+    };
+}
+
+macro_rules! make_unitary {
+    ((), then $cb:tt, struct $name:ident $($_tail:tt)*) => {
+        macro_attr_callback! { $cb, struct $name; }
+    };
+}
+
+macro_rules! make_unit_named {
+    (($name:ident), then $cb:tt, $($item:tt)*) => {
+        macro_attr_callback! { (make_unitary! { (), }, rename_to! { ($name), }, $cb), $($item)* }
+    };
+}
+
+macro_attr! {
+    #[derive(Debug)]
+    #[make_unit_named!(Unit)]
+    struct Original { field: i32 }
+}
+
+# fn main() {
+assert_eq!(format!("{:?}", Unit), "Unit");
+# }
+```
+
+Entries in the list which are themselves parenthesised callbacks or lists (such as a continuation received from `macro_attr!`) are flattened into the list.
 */
 #[macro_export]
 macro_rules! macro_attr_callback {
+    /*
+    Invokes the head of a continuation stack with the rest of the stack.
+    */
+    (@then ($($cb:tt)*), { $($cb_fixed:tt)* }, $rest:tt, $($args:tt)*) => {
+        $($cb)* { $($cb_fixed)* then $rest, $($args)* }
+    };
+
+    (@then ($($cb:tt)*), [ $($cb_fixed:tt)* ], $rest:tt, $($args:tt)*) => {
+        $($cb)* [ $($cb_fixed)* then $rest, $($args)* ]
+    };
+
+    (@then ($($cb:tt)*), ( $($cb_fixed:tt)* ), $rest:tt, $($args:tt)*) => {
+        $($cb)* ( $($cb_fixed)* then $rest, $($args)* )
+    };
+
+    (
+        ($($cb:ident)::+ ! $cb_fixed:tt, $($rest:tt)+),
+        $($args:tt)*
+    ) => {
+        $crate::macro_attr_callback! { @then ($($cb)::+ !), $cb_fixed, ($($rest)+), $($args)* }
+    };
+
+    (
+        (:: $($cb:ident)::+ ! $cb_fixed:tt, $($rest:tt)+),
+        $($args:tt)*
+    ) => {
+        $crate::macro_attr_callback! { @then (:: $($cb)::+ !), $cb_fixed, ($($rest)+), $($args)* }
+    };
+
+    (
+        ($cb_crate:tt :: $($cb:ident)::+ ! $cb_fixed:tt, $($rest:tt)+),
+        $($args:tt)*
+    ) => {
+        $crate::macro_attr_callback! { @then ($cb_crate :: $($cb)::+ !), $cb_fixed, ($($rest)+), $($args)* }
+    };
+
+    (
+        (($($head:tt)*), $($rest:tt)+),
+        $($args:tt)*
+    ) => {
+        $crate::macro_attr_callback! { ($($head)*, $($rest)+), $($args)* }
+    };
+
+    (
+        (($($head:tt)*) $(,)*),
+        $($args:tt)*
+    ) => {
+        $crate::macro_attr_callback! { ($($head)*), $($args)* }
+    };
+
+    /*
+    Invokes a single callback.
+    */
+    (
+        ($($cb:ident)::+ ! { $($cb_fixed:tt)* } $(,)*),
+        $($args:tt)*
+    ) => {
+        $($cb)::+ ! { $($cb_fixed)* $($args)* }
+    };
+
+    (
+        ($($cb:ident)::+ ! [ $($cb_fixed:tt)* ] $(,)*),
+        $($args:tt)*
+    ) => {
+        $($cb)::+ ! [ $($cb_fixed)* $($args)* ]
+    };
+
+    (
+        ($($cb:ident)::+ ! ( $($cb_fixed:tt)* ) $(,)*),
+        $($args:tt)*
+    ) => {
+        $($cb)::+ ! ( $($cb_fixed)* $($args)* )
+    };
+
+    (
+        (:: $($cb:ident)::+ ! { $($cb_fixed:tt)* } $(,)*),
+        $($args:tt)*
+    ) => {
+        :: $($cb)::+ ! { $($cb_fixed)* $($args)* }
+    };
+
+    (
+        (:: $($cb:ident)::+ ! [ $($cb_fixed:tt)* ] $(,)*),
+        $($args:tt)*
+    ) => {
+        :: $($cb)::+ ! [ $($cb_fixed)* $($args)* ]
+    };
+
+    (
+        (:: $($cb:ident)::+ ! ( $($cb_fixed:tt)* ) $(,)*),
+        $($args:tt)*
+    ) => {
+        :: $($cb)::+ ! ( $($cb_fixed)* $($args)* )
+    };
+
     (
-        ($cb:ident ! { $($cb_fixed:tt)* }),
+        ($cb_crate:tt :: $($cb:ident)::+ ! { $($cb_fixed:tt)* } $(,)*),
         $($args:tt)*
     ) => {
-        $cb! { $($cb_fixed)* $($args)* }
+        $cb_crate :: $($cb)::+ ! { $($cb_fixed)* $($args)* }
     };
 
     (
-        ($cb:ident ! [ $($cb_fixed:tt)* ]),
+        ($cb_crate:tt :: $($cb:ident)::+ ! [ $($cb_fixed:tt)* ] $(,)*),
         $($args:tt)*
     ) => {
-        $cb! [ $($cb_fixed)* $($args)* ]
+        $cb_crate :: $($cb)::+ ! [ $($cb_fixed)* $($args)* ]
     };
 
     (
-        ($cb:ident ! ( $($cb_fixed:tt)* )),
+        ($cb_crate:tt :: $($cb:ident)::+ ! ( $($cb_fixed:tt)* ) $(,)*),
         $($args:tt)*
     ) => {
-        $cb! ( $($cb_fixed)* $($args)* )
+        $cb_crate :: $($cb)::+ ! ( $($cb_fixed)* $($args)* )
     };
 }
 
 /**
-This macro provides a simple way to select between two branches of code, depending on whether or not support for procedural macros is enabled or not.
+This macro defines a *derivation bundle*: a name for a list of derivations which are commonly used together.
+
+```ignore
+macro_attr_bundle! {
+    Numeric = Copy, Clone, NewtypeAdd!, NewtypeSub!, NewtypeMul!(*), NewtypeDiv!(*)
+}
+```
+
+The bundle is defined as a macro which can be used in place of its members, either as a derivation or as a macro attribute:
+
+- `#[derive(Numeric!)]` invokes each of the bundle's macro derivations.  Because a derivation cannot alter the item it is attached to, a bundle used this way may *only* contain macro derivations (and, if procedural macro support is disabled, hybrid derivations).
+- `#[Numeric!]` adds `#[derive(...)]` with all of the bundle's members to the item.  This works for any kind of derivation, including built-in ones.
+
+Members may themselves be bundles.  Any attributes (such as `#[macro_export]`) written before the bundle's name are attached to the resulting macro.
+
+```rust
+# #[macro_use] extern crate macro_attr;
+macro_rules! Name {
+    (() $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name { fn name() -> &'static str { stringify!($name) } }
+    };
+}
+
+macro_rules! Unit {
+    (() $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name { fn unit() -> Self { $name } }
+    };
+}
+
+macro_attr_bundle! { Named = Name!, Unit! }
+macro_attr_bundle! { Common = Debug, PartialEq, Named! }
+
+macro_attr! {
+    #[derive(Named!)]
+    struct First;
+}
+
+macro_attr! {
+    #[Common!]
+    struct Second;
+}
+
+# fn main() {
+assert_eq!(First::name(), "First");
+assert_eq!(Second::unit(), Second);
+# }
+```
 */
-#[doc(hidden)]
 #[macro_export]
-#[cfg(feature="unstable-macros-1-1")]
+macro_rules! macro_attr_bundle {
+    (
+        $(#[$($attrs:tt)*])*
+        $name:ident = $($members:tt)*
+    ) => {
+        $crate::macro_attr_impl! {
+            @bundle_def ($), ($(#[$($attrs)*])*), $name, ($($members)*)
+        }
+    };
+}
+
+/**
+This macro feeds an item emitted by a derivation or macro attribute back through `macro_attr!`, so that it can have derivations and macro attributes of its own.
+
+It must be given either the derivation context received by a context derivation (*i.e.* one written as `Name@!`), or the callback received by a macro attribute, followed by a comma and a single item.  These are used to keep track of how deeply items are nested: to keep a derivation which (directly or indirectly) derives itself from looping until the recursion limit is reached, items may only be nested four levels deep.  Note that each level uses a considerable amount of the recursion limit, so an item with many attributes and derivations may reach it first.
+
+```rust
+# #[macro_use] extern crate macro_attr;
+macro_rules! Name {
+    (() $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name { pub fn name() -> &'static str { stringify!($name) } }
+    };
+}
+
+macro_rules! Builder {
+    (($builder:ident) $ctx:tt $(pub)* struct $name:ident $($_tail:tt)*) => {
+        macro_attr_nested! {
+            $ctx,
+            #[derive(Default, Name!)]
+            pub struct $builder;
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Builder@!(FooBuilder))]
+    struct Foo;
+}
+
+# fn main() {
+assert_eq!(FooBuilder::name(), "FooBuilder");
+# let _ = FooBuilder::default();
+# }
+```
+
+A derivation which keeps re-entering itself results in an error:
+
+```compile_fail
+# #[macro_use] extern crate macro_attr;
+macro_rules! Forever {
+    (() $ctx:tt struct $name:ident;) => {
+        macro_attr_nested! { $ctx, #[derive(Forever@!)] struct Again; }
+    };
+}
+
+macro_attr! {
+    #[derive(Forever@!)]
+    struct Foo;
+}
+# fn main() {}
+```
+
+For items emitted this way, the context has an extra, final entry recording the depth as `depth(...)`, containing one `+` for each level of nesting.  Items at the top level have no `depth` entry at all.  Macro attributes on fields or variants cannot use `macro_attr_nested!`.
+*/
+#[macro_export]
+macro_rules! macro_attr_nested {
+    ($ctx:tt, $($it:tt)*) => {
+        $crate::macro_attr_impl! { @nested $ctx, ($($it)*) }
+    };
+}
+
+/**
+This macro emits items generated by a derivation with the lint attributes of the item they were derived from.
+
+It must be given the derivation context received by a context derivation (*i.e.* one written as `Name@!`), followed by a comma and any number of items.  Each item is emitted with the `#[allow(...)]` attributes of the original item, plus `#[allow(deprecated)]` if the original item is `#[deprecated]`.  This lets a derivation refer to a deprecated item without causing warnings.
+
+```rust
+#![deny(warnings)]
+# #[macro_use] extern crate macro_attr;
+macro_rules! Name {
+    (() $ctx:tt $(pub)* struct $name:ident $($_tail:tt)*) => {
+        macro_attr_inherit! {
+            $ctx,
+            impl $name { pub fn name() -> &'static str { stringify!($name) } }
+        }
+    };
+}
+
+macro_attr! {
+    #[deprecated(note = "use `Bar` instead")]
+    #[derive(Name@!)]
+    pub struct Foo;
+}
+
+# #[allow(deprecated)]
+# fn main() {
+assert_eq!(Foo::name(), "Foo");
+# }
+```
+*/
+#[macro_export]
+macro_rules! macro_attr_inherit {
+    ($ctx:tt, $($items:tt)*) => {
+        $crate::macro_attr_impl! { @inherit $ctx, ($($items)*) }
+    };
+}
+
+/**
+This macro provides a simple way to select between two branches of code, depending on whether or not the `macro-attr-proc` feature is enabled.
+*/
+#[macro_export]
+#[cfg(feature = "macro-attr-proc")]
 macro_rules! macro_attr_if_proc_macros {
     (
         proc_macros: { $($items:item)* }
@@ -978,11 +3289,10 @@ macro_rules! macro_attr_if_proc_macros {
 }
 
 /**
-This macro provides a simple way to select between two branches of code, depending on whether or not support for procedural macros is enabled or not.
+This macro provides a simple way to select between two branches of code, depending on whether or not the `macro-attr-proc` feature is enabled.
 */
-#[doc(hidden)]
 #[macro_export]
-#[cfg(not(feature="unstable-macros-1-1"))]
+#[cfg(not(feature = "macro-attr-proc"))]
 macro_rules! macro_attr_if_proc_macros {
     (
         proc_macros: $_ignore:tt
@@ -0,0 +1,945 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+/*!
+Callback-style parsers for use by macro derivations and attributes.
+*/
+
+/**
+Parses a `struct`, `enum` or `union` definition into its component parts, then passes those parts to a callback.
+
+This exists so that derivation macros do not each have to re-implement the (surprisingly fiddly) job of pulling generic parameters and `where` clauses apart.  A derivation can opt into a pre-parsed calling convention by forwarding its input here:
+
+```rust
+#[macro_use] extern crate macro_attr;
+
+trait TypeName {
+    fn type_name() -> &'static str;
+}
+
+macro_rules! TypeName {
+    (() $($item:tt)*) => {
+        macro_attr_parse_item! { (TypeName! { @parsed }), $($item)* }
+    };
+
+    (
+        @parsed
+        attrs: $_attrs:tt,
+        vis: $_vis:tt,
+        kind: $_kind:ident,
+        name: $name:ident,
+        generics: $_generics:tt,
+        impl_generics: ($($impl_gen:tt)*),
+        ty_generics: ($($ty_gen:tt)*),
+        ty_params: ($($ty_params:ident,)*),
+        where: ($($where_:tt)*),
+        body: $_body:tt
+    ) => {
+        impl<$($impl_gen)*> TypeName for $name<$($ty_gen)*>
+        where $($where_)* $($ty_params: 'static,)* {
+            fn type_name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(TypeName!)]
+    struct Wrapper<'a, T: Clone + 'a = u8>(&'a T) where T: PartialEq;
+}
+
+# fn main() {
+assert_eq!(<Wrapper<'static, u8> as TypeName>::type_name(), "Wrapper");
+# }
+```
+
+# Input
+
+The first argument is the callback, in the same form accepted by `macro_attr_callback!`.  The rest of the input is the item itself, optionally preceded by attributes and a visibility.
+
+# Output
+
+The callback is invoked with the following, in order:
+
+- `attrs: (...)`: the item's attributes, as written.
+- `vis: (...)`: the item's visibility, captured as a `vis` fragment; *e.g.* `(pub(crate))` or `()`.
+- `kind: ...`: one of `struct`, `enum` or `union`.
+- `name: ...`: the name of the item.
+- `generics: (...)`: the generic parameters as they appear in the item definition, including bounds and defaults.
+- `impl_generics: (...)`: the generic parameters with bounds but *without* defaults, suitable for `impl<...>`.
+- `ty_generics: (...)`: just the names of the generic parameters, suitable for `Name<...>`.
+- `ty_params: (...)`: just the names of the *type* parameters; lifetimes and constants are omitted.
+- `where: (...)`: the predicates of the `where` clause, if any.
+- `body: (...)`: the body of the item.  This is either a `{...}` group, a `(...)` group for tuple structs, or nothing for unit structs.
+
+Every list is normalised to have a trailing comma after each entry, so `impl<$($impl_gen)*>` and `where $($where_)* T: Extra,` are always valid, even when the lists are empty.
+*/
+#[macro_export]
+macro_rules! macro_attr_parse_item {
+    ($cb:tt, $($item:tt)*) => {
+        $crate::macro_attr_parse_impl! { @item $cb, $($item)* }
+    };
+}
+
+/**
+Parses the contents of a braced `struct` body (or struct-like enum variant) into a list of fields, then passes that list to a callback.
+
+```rust
+#[macro_use] extern crate macro_attr;
+
+macro_rules! field_names {
+    (fields: ($((attrs: $_attrs:tt, vis: $_vis:tt, name: $name:ident, ty: $_ty:ty),)*)) => {
+        fn field_names() -> Vec<&'static str> { vec![$(stringify!($name)),*] }
+    };
+}
+
+macro_attr_parse_named_fields! {
+    (field_names! {}),
+    /// Doc comments are attributes, too.
+    pub a: i32,
+    b: Vec<(u8, u8)>,
+}
+
+# fn main() {
+assert_eq!(field_names(), vec!["a", "b"]);
+# }
+```
+
+The callback is invoked with `fields: (...)`, where each field is given as `(attrs: (...), vis: (...), name: ..., ty: ...),`.
+*/
+#[macro_export]
+macro_rules! macro_attr_parse_named_fields {
+    ($cb:tt, $($fields:tt)*) => {
+        $crate::macro_attr_parse_impl! { @named_fields $cb, ($($fields)*,) -> () }
+    };
+}
+
+/**
+Parses the contents of a tuple `struct` body (or tuple-like enum variant) into a list of fields, then passes that list to a callback.
+
+```rust
+#[macro_use] extern crate macro_attr;
+
+struct Pair(u8, u16);
+
+macro_rules! sum_fields {
+    (fields: ($((attrs: $_attrs:tt, vis: $_vis:tt, index: $idx:tt, ty: $_ty:ty),)*)) => {
+        fn sum_fields(p: &Pair) -> u32 { 0 $(+ p.$idx as u32)* }
+    };
+}
+
+macro_attr_parse_tuple_fields! { (sum_fields! {}), u8, u16 }
+
+# fn main() {
+assert_eq!(sum_fields(&Pair(1, 2)), 3);
+# }
+```
+
+The callback is invoked with `fields: (...)`, where each field is given as `(attrs: (...), vis: (...), index: ..., ty: ...),`.  The `index` is an integer literal usable for field access (*i.e.* `self.$index`); because these have to be taken from a fixed table, at most 32 fields are supported.
+*/
+#[macro_export]
+macro_rules! macro_attr_parse_tuple_fields {
+    ($cb:tt, $($fields:tt)*) => {
+        $crate::macro_attr_parse_impl! {
+            @tuple_fields $cb,
+            (0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31),
+            ($($fields)*,) -> ()
+        }
+    };
+}
+
+/**
+Parses the contents of an `enum` body into a list of variants, then passes that list to a callback.
+
+```rust
+#[macro_use] extern crate macro_attr;
+
+macro_rules! unit_variants {
+    (variants: ($((attrs: $_attrs:tt, name: $name:ident, kind: $kind:ident, body: $_body:tt, discriminant: $_disc:tt),)*)) => {
+        fn variant_kinds() -> Vec<(&'static str, &'static str)> {
+            vec![$((stringify!($name), stringify!($kind))),*]
+        }
+    };
+}
+
+macro_attr_parse_variants! {
+    (unit_variants! {}),
+    A,
+    B = 42,
+    C(i32, i32),
+    D { x: i32 }
+}
+
+# fn main() {
+assert_eq!(variant_kinds(), vec![("A", "unit"), ("B", "unit"), ("C", "tuple"), ("D", "struct")]);
+# }
+```
+
+The callback is invoked with `variants: (...)`, where each variant is given as `(attrs: (...), name: ..., kind: ..., body: (...), discriminant: (...)),`.
+
+- `kind` is one of `unit`, `tuple` or `struct`.
+- `body` contains the variant's `(...)` or `{...}` group, or nothing for unit variants.  The contents can be passed to `macro_attr_parse_tuple_fields!` or `macro_attr_parse_named_fields!` respectively.
+- `discriminant` contains the explicit discriminant expression, or nothing.
+*/
+#[macro_export]
+macro_rules! macro_attr_parse_variants {
+    ($cb:tt, $($variants:tt)*) => {
+        $crate::macro_attr_parse_impl! { @variants $cb, ($($variants)*,) -> () }
+    };
+}
+
+/**
+Parses the arguments of a macro derivation or attribute into positional and keyed arguments, then passes them to a callback.
+
+Arguments are separated by commas.  Any arguments before the first `key = value` argument are *positional*; after that, every argument must either be another `key = value`, or a lone identifier, which is a *flag* and is treated as `flag = true`.  Note that this means a lone identifier written *before* any `key = value` argument is positional, not a flag.  This lets a derivation accept optional keyed arguments in any order, handling each key with a single rule rather than one rule per combination:
+
+```rust
+#[macro_use] extern crate macro_attr;
+
+trait Describe {
+    fn describe() -> String;
+}
+
+macro_rules! Describe {
+    (($($args:tt)*) $($item:tt)*) => {
+        macro_attr_parse_args! { (Describe! { @args ($($item)*) }), $($args)* }
+    };
+
+    (
+        @args $item:tt
+        positional: (($label:expr),),
+        keyed: $keyed:tt
+    ) => {
+        Describe! { @keyed $item, ($label, ", ", false), $keyed }
+    };
+
+    // Fold over the keyed arguments, starting from the defaults.
+    (@keyed $item:tt, ($label:expr, $_sep:expr, $upper:expr), ((name: sep, value: ($sep:expr)), $($tail:tt)*)) => {
+        Describe! { @keyed $item, ($label, $sep, $upper), ($($tail)*) }
+    };
+
+    (@keyed $item:tt, ($label:expr, $sep:expr, $_upper:expr), ((name: upper, value: ($upper:expr)), $($tail:tt)*)) => {
+        Describe! { @keyed $item, ($label, $sep, $upper), ($($tail)*) }
+    };
+
+    (@keyed (struct $name:ident;), ($label:expr, $sep:expr, $upper:expr), ()) => {
+        impl Describe for $name {
+            fn describe() -> String {
+                let s = format!("{}{}{}", $label, $sep, stringify!($name));
+                if $upper { s.to_uppercase() } else { s }
+            }
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Describe!("unit"))]
+    struct A;
+}
+
+macro_attr! {
+    #[derive(Describe!("unit", sep = ": ", upper))]
+    struct B;
+}
+
+# fn main() {
+assert_eq!(A::describe(), "unit, A");
+assert_eq!(B::describe(), "UNIT: B");
+# }
+```
+
+The callback is invoked with `positional: (...), keyed: (...)`.  Each positional argument is given as `(...),`, containing the tokens of the argument.  Each keyed argument or flag is given as `(name: ..., value: (...)),`, in the order they were written.
+
+Commas nested inside `<...>` do not separate arguments, so a type such as `HashMap<K, V>` can be passed as-is.  This means a `<` used as a comparison must be wrapped in parentheses.
+*/
+#[macro_export]
+macro_rules! macro_attr_parse_args {
+    ($cb:tt, $($args:tt)*) => {
+        $crate::macro_attr_parse_impl! { @args_pos $cb, ($($args)*,) -> (), () }
+    };
+}
+
+/**
+This macro exists as an implementation detail of the `macro_attr_parse_*!` macros.
+*/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! macro_attr_parse_impl {
+    /*
+
+    # `@item`
+
+    Splits off the attributes, visibility, kind and name of the item.
+
+    */
+    (
+        @item $cb:tt,
+        $(#[$($attrs:tt)*])*
+        $vis:vis enum $name:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_generics
+            ($cb, ($(#[$($attrs)*])*), ($vis), enum, $name),
+            ($($tail)*)
+        }
+    };
+
+    (
+        @item $cb:tt,
+        $(#[$($attrs:tt)*])*
+        $vis:vis struct $name:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_generics
+            ($cb, ($(#[$($attrs)*])*), ($vis), struct, $name),
+            ($($tail)*)
+        }
+    };
+
+    (
+        @item $cb:tt,
+        $(#[$($attrs:tt)*])*
+        $vis:vis union $name:ident $($tail:tt)*
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_generics
+            ($cb, ($(#[$($attrs)*])*), ($vis), union, $name),
+            ($($tail)*)
+        }
+    };
+
+    (
+        @item $_cb:tt,
+        $($item:tt)*
+    ) => {
+        compile_error!(concat!(
+            "macro_attr_parse_item!: expected a struct, enum or union, found `",
+            stringify!($($item)*), "`"
+        ));
+    };
+
+    /*
+
+    # `@item_generics`
+
+    Parses the generic parameter list one parameter at a time.  Each parameter is accumulated three ways: as written (`decl`), without any default (`impl`), and as just its name (`ty`).  Type parameters are additionally collected into `tps`.
+
+    */
+    (
+        @item_generics $fixed:tt,
+        (< $($tail:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_param $fixed,
+            ($($tail)*) -> ((), (), (), ())
+        }
+    };
+
+    (
+        @item_generics $fixed:tt,
+        ($($tail:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_body $fixed, ((), (), (), ()),
+            ($($tail)*)
+        }
+    };
+
+    (
+        @item_gen_param $fixed:tt,
+        (> $($tail:tt)*) -> $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_body $fixed, $gens,
+            ($($tail)*)
+        }
+    };
+
+    (
+        @item_gen_param $fixed:tt,
+        (const $param:ident : $($tail:tt)*) -> $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_bound $fixed, ((const $param), $param, ()), (),
+            ($($tail)*) -> (:), $gens
+        }
+    };
+
+    (
+        @item_gen_param $fixed:tt,
+        ($param:ident : $($tail:tt)*) -> $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_bound $fixed, (($param), $param, ($param,)), (),
+            ($($tail)*) -> (:), $gens
+        }
+    };
+
+    (
+        @item_gen_param $fixed:tt,
+        ($param:ident $($tail:tt)*) -> $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_bound $fixed, (($param), $param, ($param,)), (),
+            ($($tail)*) -> (), $gens
+        }
+    };
+
+    (
+        @item_gen_param $fixed:tt,
+        ($lt:tt : $($tail:tt)*) -> $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_bound $fixed, (($lt), $lt, ()), (),
+            ($($tail)*) -> (:), $gens
+        }
+    };
+
+    (
+        @item_gen_param $fixed:tt,
+        ($lt:tt $($tail:tt)*) -> $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_bound $fixed, (($lt), $lt, ()), (),
+            ($($tail)*) -> (), $gens
+        }
+    };
+
+    /*
+
+    ## `@item_gen_bound`
+
+    Collects the bounds of a single parameter.  The second argument is a stack of `+`s used to track how deeply nested inside `<...>` we are, so that commas inside things like `T: Into<Vec<(A, B)>>` don't end the parameter early.
+
+    */
+    (
+        @item_gen_bound $fixed:tt, $param:tt, (),
+        (, $($tail:tt)*) -> $bound:tt, $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_push $fixed, $param, $bound, (),
+            ($($tail)*) -> $gens
+        }
+    };
+
+    (
+        @item_gen_bound $fixed:tt, $param:tt, (),
+        (> $($tail:tt)*) -> $bound:tt, $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_push $fixed, $param, $bound, (),
+            (> $($tail)*) -> $gens
+        }
+    };
+
+    (
+        @item_gen_bound $fixed:tt, $param:tt, (),
+        (= $($tail:tt)*) -> $bound:tt, $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_default $fixed, $param, (),
+            ($($tail)*) -> $bound, (=), $gens
+        }
+    };
+
+    (
+        @item_gen_bound $fixed:tt, $param:tt, ($($depth:tt)*),
+        (< $($tail:tt)*) -> ($($bound:tt)*), $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_bound $fixed, $param, (+ $($depth)*),
+            ($($tail)*) -> ($($bound)* <), $gens
+        }
+    };
+
+    (
+        @item_gen_bound $fixed:tt, $param:tt, (+ $($depth:tt)*),
+        (> $($tail:tt)*) -> ($($bound:tt)*), $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_bound $fixed, $param, ($($depth)*),
+            ($($tail)*) -> ($($bound)* >), $gens
+        }
+    };
+
+    (
+        @item_gen_bound $fixed:tt, $param:tt, $depth:tt,
+        (>> $($tail:tt)*) -> $bound:tt, $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_bound $fixed, $param, $depth,
+            (> > $($tail)*) -> $bound, $gens
+        }
+    };
+
+    (
+        @item_gen_bound $fixed:tt, $param:tt, $depth:tt,
+        ($other:tt $($tail:tt)*) -> ($($bound:tt)*), $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_bound $fixed, $param, $depth,
+            ($($tail)*) -> ($($bound)* $other), $gens
+        }
+    };
+
+    /*
+
+    ## `@item_gen_default`
+
+    As for `@item_gen_bound`, except that the tokens collected are the parameter's default, which is only kept in `decl`.
+
+    */
+    (
+        @item_gen_default $fixed:tt, $param:tt, (),
+        (, $($tail:tt)*) -> $bound:tt, $default:tt, $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_push $fixed, $param, $bound, $default,
+            ($($tail)*) -> $gens
+        }
+    };
+
+    (
+        @item_gen_default $fixed:tt, $param:tt, (),
+        (> $($tail:tt)*) -> $bound:tt, $default:tt, $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_push $fixed, $param, $bound, $default,
+            (> $($tail)*) -> $gens
+        }
+    };
+
+    (
+        @item_gen_default $fixed:tt, $param:tt, ($($depth:tt)*),
+        (< $($tail:tt)*) -> $bound:tt, ($($default:tt)*), $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_default $fixed, $param, (+ $($depth)*),
+            ($($tail)*) -> $bound, ($($default)* <), $gens
+        }
+    };
+
+    (
+        @item_gen_default $fixed:tt, $param:tt, (+ $($depth:tt)*),
+        (> $($tail:tt)*) -> $bound:tt, ($($default:tt)*), $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_default $fixed, $param, ($($depth)*),
+            ($($tail)*) -> $bound, ($($default)* >), $gens
+        }
+    };
+
+    (
+        @item_gen_default $fixed:tt, $param:tt, $depth:tt,
+        (>> $($tail:tt)*) -> $bound:tt, $default:tt, $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_default $fixed, $param, $depth,
+            (> > $($tail)*) -> $bound, $default, $gens
+        }
+    };
+
+    (
+        @item_gen_default $fixed:tt, $param:tt, $depth:tt,
+        ($other:tt $($tail:tt)*) -> $bound:tt, ($($default:tt)*), $gens:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_default $fixed, $param, $depth,
+            ($($tail)*) -> $bound, ($($default)* $other), $gens
+        }
+    };
+
+    /*
+
+    ## `@item_gen_push`
+
+    Adds a fully parsed parameter to each of the accumulated lists.
+
+    */
+    (
+        @item_gen_push $fixed:tt,
+        (($($head:tt)*), $name:tt, ($($tp:tt)*)),
+        ($($bound:tt)*), ($($default:tt)*),
+        $tail:tt -> (($($decl:tt)*), ($($impl_:tt)*), ($($ty:tt)*), ($($tps:tt)*))
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_gen_param $fixed,
+            $tail -> (
+                ($($decl)* $($head)* $($bound)* $($default)*,),
+                ($($impl_)* $($head)* $($bound)*,),
+                ($($ty)* $name,),
+                ($($tps)* $($tp)*)
+            )
+        }
+    };
+
+    /*
+
+    # `@item_body`
+
+    Works out what shape of body the item has.  Tuple structs can have a `where` clause *after* the body, which is why the body is carried through `@item_where` as a pending value.
+
+    */
+    (
+        @item_body $fixed:tt, $gens:tt,
+        ({ $($body:tt)* })
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_emit $fixed, $gens, (), ({ $($body)* })
+        }
+    };
+
+    (
+        @item_body $fixed:tt, $gens:tt,
+        (;)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_emit $fixed, $gens, (), ()
+        }
+    };
+
+    (
+        @item_body $fixed:tt, $gens:tt,
+        (($($body:tt)*);)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_emit $fixed, $gens, (), (($($body)*))
+        }
+    };
+
+    (
+        @item_body $fixed:tt, $gens:tt,
+        (($($body:tt)*) where $($tail:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_where $fixed, $gens, (($($body)*)),
+            ($($tail)*) -> (), ()
+        }
+    };
+
+    (
+        @item_body $fixed:tt, $gens:tt,
+        (where $($tail:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_where $fixed, $gens, (),
+            ($($tail)*) -> (), ()
+        }
+    };
+
+    /*
+
+    ## `@item_where`
+
+    Collects `where` predicates up to the body or terminating semicolon.  The last argument holds the comma (if any) needed to terminate the predicates collected so far.
+
+    */
+    (
+        @item_where $fixed:tt, $gens:tt, $_pending:tt,
+        ({ $($body:tt)* }) -> $where_:tt, $comma:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_where_end $fixed, $gens, ({ $($body)* }), $where_, $comma
+        }
+    };
+
+    (
+        @item_where $fixed:tt, $gens:tt, $pending:tt,
+        (;) -> $where_:tt, $comma:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_where_end $fixed, $gens, $pending, $where_, $comma
+        }
+    };
+
+    (
+        @item_where $fixed:tt, $gens:tt, $pending:tt,
+        (, $($tail:tt)*) -> ($($where_:tt)*), $_comma:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_where $fixed, $gens, $pending,
+            ($($tail)*) -> ($($where_)* ,), ()
+        }
+    };
+
+    (
+        @item_where $fixed:tt, $gens:tt, $pending:tt,
+        ($other:tt $($tail:tt)*) -> ($($where_:tt)*), $_comma:tt
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_where $fixed, $gens, $pending,
+            ($($tail)*) -> ($($where_)* $other), (,)
+        }
+    };
+
+    (
+        @item_where_end $fixed:tt, $gens:tt, $body:tt,
+        ($($where_:tt)*), ($($comma:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @item_emit $fixed, $gens, ($($where_)* $($comma)*), $body
+        }
+    };
+
+    /*
+
+    # `@item_emit`
+
+    Hands the parsed item to the callback.
+
+    */
+    (
+        @item_emit
+        ($cb:tt, $attrs:tt, $vis:tt, $kind:ident, $name:ident),
+        ($decl:tt, $impl_:tt, $ty:tt, $tps:tt),
+        $where_:tt, $body:tt
+    ) => {
+        $crate::macro_attr_callback! {
+            $cb,
+            attrs: $attrs,
+            vis: $vis,
+            kind: $kind,
+            name: $name,
+            generics: $decl,
+            impl_generics: $impl_,
+            ty_generics: $ty,
+            ty_params: $tps,
+            where: $where_,
+            body: $body
+        }
+    };
+
+    /*
+
+    # `@named_fields`
+
+    Parses one named field at a time.  A comma is appended to the input by the caller so that every field is comma-terminated.
+
+    */
+    (
+        @named_fields $cb:tt,
+        ($(,)*) -> ($($fields:tt)*)
+    ) => {
+        $crate::macro_attr_callback! { $cb, fields: ($($fields)*) }
+    };
+
+    (
+        @named_fields $cb:tt,
+        ($(#[$($attrs:tt)*])* $vis:vis $name:ident: $ty:ty, $($tail:tt)*) -> ($($fields:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @named_fields $cb,
+            ($($tail)*) -> ($($fields)* (attrs: ($(#[$($attrs)*])*), vis: ($vis), name: $name, ty: $ty),)
+        }
+    };
+
+    /*
+
+    # `@tuple_fields`
+
+    As for `@named_fields`, except that each field is numbered using the next literal from the table of indices.
+
+    */
+    (
+        @tuple_fields $cb:tt, $_idxs:tt,
+        ($(,)*) -> ($($fields:tt)*)
+    ) => {
+        $crate::macro_attr_callback! { $cb, fields: ($($fields)*) }
+    };
+
+    (
+        @tuple_fields $cb:tt, ($idx:tt $($idxs:tt)*),
+        ($(#[$($attrs:tt)*])* $vis:vis $ty:ty, $($tail:tt)*) -> ($($fields:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @tuple_fields $cb, ($($idxs)*),
+            ($($tail)*) -> ($($fields)* (attrs: ($(#[$($attrs)*])*), vis: ($vis), index: $idx, ty: $ty),)
+        }
+    };
+
+    /*
+
+    # `@variants`
+
+    Parses one enum variant at a time, classifying it by the shape of its body.
+
+    */
+    (
+        @variants $cb:tt,
+        ($(,)*) -> ($($variants:tt)*)
+    ) => {
+        $crate::macro_attr_callback! { $cb, variants: ($($variants)*) }
+    };
+
+    (
+        @variants $cb:tt,
+        ($(#[$($attrs:tt)*])* $name:ident ($($body:tt)*), $($tail:tt)*) -> ($($variants:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @variants $cb,
+            ($($tail)*) -> ($($variants)*
+                (attrs: ($(#[$($attrs)*])*), name: $name, kind: tuple, body: (($($body)*)), discriminant: ()),)
+        }
+    };
+
+    (
+        @variants $cb:tt,
+        ($(#[$($attrs:tt)*])* $name:ident { $($body:tt)* }, $($tail:tt)*) -> ($($variants:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @variants $cb,
+            ($($tail)*) -> ($($variants)*
+                (attrs: ($(#[$($attrs)*])*), name: $name, kind: struct, body: ({ $($body)* }), discriminant: ()),)
+        }
+    };
+
+    (
+        @variants $cb:tt,
+        ($(#[$($attrs:tt)*])* $name:ident = $disc:expr, $($tail:tt)*) -> ($($variants:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @variants $cb,
+            ($($tail)*) -> ($($variants)*
+                (attrs: ($(#[$($attrs)*])*), name: $name, kind: unit, body: (), discriminant: ($disc)),)
+        }
+    };
+
+    (
+        @variants $cb:tt,
+        ($(#[$($attrs:tt)*])* $name:ident, $($tail:tt)*) -> ($($variants:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @variants $cb,
+            ($($tail)*) -> ($($variants)*
+                (attrs: ($(#[$($attrs)*])*), name: $name, kind: unit, body: (), discriminant: ()),)
+        }
+    };
+    /*
+
+    # `@args_pos`
+
+    Parses positional arguments until the first keyed argument.  A comma is appended to the input by the caller so that every argument is comma-terminated.
+
+    */
+    (
+        @args_pos $cb:tt,
+        ($(,)*) -> $pos:tt, $keyed:tt
+    ) => {
+        $crate::macro_attr_callback! { $cb, positional: $pos, keyed: $keyed }
+    };
+
+    (
+        @args_pos $cb:tt,
+        ($key:ident = $($tail:tt)*) -> $pos:tt, $keyed:tt
+    ) => {
+        $crate::macro_attr_parse_impl! { @args_collect ($cb, $pos, $keyed, keyed $key), (), ($($tail)*) -> () }
+    };
+
+    (
+        @args_pos $cb:tt,
+        ($($tail:tt)*) -> $pos:tt, $keyed:tt
+    ) => {
+        $crate::macro_attr_parse_impl! { @args_collect ($cb, $pos, $keyed, positional), (), ($($tail)*) -> () }
+    };
+
+    /*
+
+    # `@args_keyed`
+
+    Parses the remaining keyed arguments and flags.
+
+    */
+    (
+        @args_keyed $cb:tt,
+        ($(,)*) -> $pos:tt, $keyed:tt
+    ) => {
+        $crate::macro_attr_callback! { $cb, positional: $pos, keyed: $keyed }
+    };
+
+    (
+        @args_keyed $cb:tt,
+        ($key:ident = $($tail:tt)*) -> $pos:tt, $keyed:tt
+    ) => {
+        $crate::macro_attr_parse_impl! { @args_collect ($cb, $pos, $keyed, keyed $key), (), ($($tail)*) -> () }
+    };
+
+    (
+        @args_keyed $cb:tt,
+        ($flag:ident, $($tail:tt)*) -> $pos:tt, ($($keyed:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! {
+            @args_keyed $cb,
+            ($($tail)*) -> $pos, ($($keyed)* (name: $flag, value: (true)),)
+        }
+    };
+
+    (
+        @args_keyed $cb:tt,
+        ($($tail:tt)*) -> $_pos:tt, $_keyed:tt
+    ) => {
+        compile_error!(concat!(
+            "macro_attr_parse_args!: expected `key = value` or a flag, found `",
+            stringify!($($tail)*),
+            "`; positional arguments must come before any keyed arguments or flags"
+        ));
+    };
+
+    /*
+
+    # `@args_collect`
+
+    Collects the tokens of a single argument up to the next comma, then pushes it onto the appropriate list.  As with `@item_gen_bound`, the second argument is a stack of `+`s tracking how deeply nested inside `<...>` we are, so that the comma in something like `HashMap<K, V>` doesn't end the argument early.
+
+    */
+    (
+        @args_collect $fixed:tt, (),
+        (, $($tail:tt)*) -> $arg:tt
+    ) => {
+        $crate::macro_attr_parse_impl! { @args_push $fixed, $arg, ($($tail)*) }
+    };
+
+    (
+        @args_collect $fixed:tt, ($($depth:tt)*),
+        (< $($tail:tt)*) -> ($($arg:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! { @args_collect $fixed, (+ $($depth)*), ($($tail)*) -> ($($arg)* <) }
+    };
+
+    (
+        @args_collect $fixed:tt, (+ $($depth:tt)*),
+        (> $($tail:tt)*) -> ($($arg:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! { @args_collect $fixed, ($($depth)*), ($($tail)*) -> ($($arg)* >) }
+    };
+
+    (
+        @args_collect $fixed:tt, (+ $($depth:tt)*),
+        (>> $($tail:tt)*) -> $arg:tt
+    ) => {
+        $crate::macro_attr_parse_impl! { @args_collect $fixed, (+ $($depth)*), (> > $($tail)*) -> $arg }
+    };
+
+    (
+        @args_collect $fixed:tt, $depth:tt,
+        ($next:tt $($tail:tt)*) -> ($($arg:tt)*)
+    ) => {
+        $crate::macro_attr_parse_impl! { @args_collect $fixed, $depth, ($($tail)*) -> ($($arg)* $next) }
+    };
+
+    (
+        @args_push ($cb:tt, ($($pos:tt)*), $keyed:tt, positional), $arg:tt, $tail:tt
+    ) => {
+        $crate::macro_attr_parse_impl! { @args_pos $cb, $tail -> ($($pos)* $arg,), $keyed }
+    };
+
+    (
+        @args_push ($cb:tt, $pos:tt, ($($keyed:tt)*), keyed $key:ident), $arg:tt, $tail:tt
+    ) => {
+        $crate::macro_attr_parse_impl! { @args_keyed $cb, $tail -> $pos, ($($keyed)* (name: $key, value: $arg),) }
+    };
+}
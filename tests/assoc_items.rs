@@ -0,0 +1,115 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! rename_to {
+    (($new:ident), then $cb:tt, $(#[$($attrs:tt)*])* fn $_old:ident $($tail:tt)*) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* fn $new $($tail)* }
+    };
+
+    (($new:ident), then $cb:tt, $(#[$($attrs:tt)*])* pub fn $_old:ident $($tail:tt)*) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* pub fn $new $($tail)* }
+    };
+
+    (($new:ident), then $cb:tt, $(#[$($attrs:tt)*])* const $_old:ident $($tail:tt)*) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* const $new $($tail)* }
+    };
+
+    (($new:ident), then $cb:tt, $(#[$($attrs:tt)*])* type $_old:ident $($tail:tt)*) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* type $new $($tail)* }
+    };
+}
+
+/*
+Turns a function with no arguments into an associated constant holding its result.
+*/
+macro_rules! as_const {
+    (($name:ident), then $cb:tt, $(#[$($attrs:tt)*])* fn $_fn:ident() -> $ty:ty { $e:expr }) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* const $name: $ty = $e; }
+    };
+}
+
+macro_rules! Twice {
+    (() pub fn $name:ident(&self) -> u32 $_body:tt) => {
+        pub fn twice(&self) -> u32 { 2 * self.$name() }
+    };
+}
+
+macro_rules! Times {
+    (($method:ident, $n:expr) pub fn $name:ident(&self) -> u32 $_body:tt) => {
+        pub fn $method(&self) -> u32 { $n * self.$name() }
+    };
+}
+
+pub struct Thing(u32);
+
+macro_attr! {
+    impl Thing {
+        #![allow(dead_code)]
+
+        #[rename_to!(value)]
+        #[derive(Twice!, Times!(thrice, 3), Times!(four_times, 4))]
+        /// Doc comments are kept.
+        pub fn old_value(&self) -> u32 { self.0 }
+
+        #[as_const!(ANSWER)]
+        fn answer() -> u32 { 42 }
+
+        #[rename_to!(UNIT)]
+        const OLD_UNIT: u32 = 1;
+
+        fn plain(&self) -> u32 { self.value() + Self::UNIT }
+    }
+
+    pub trait Describe {
+        #[rename_to!(Output)]
+        type OldOutput;
+
+        #[rename_to!(describe)]
+        fn old_describe(&self) -> Self::Output;
+
+        #[rename_to!(describe_twice)]
+        fn old_describe_twice(&self) -> (Self::Output, Self::Output) {
+            (self.describe(), self.describe())
+        }
+    }
+
+    impl<T> Describe for Option<T> where T: Clone {
+        #[rename_to!(Output)]
+        type OldOutput = bool;
+
+        #[rename_to!(describe)]
+        fn old_describe(&self) -> bool { self.is_some() }
+    }
+
+    trait Named {
+        #[rename_to!(name)]
+        fn old_name(&self) -> &'static str;
+    }
+
+    impl Named for Thing {
+        #[rename_to!(name)]
+        fn old_name(&self) -> &'static str { "thing" }
+    }
+}
+
+#[test]
+fn test_assoc_items() {
+    let thing = Thing(3);
+    assert_eq!(thing.value(), 3);
+    assert_eq!(thing.twice(), 6);
+    assert_eq!((thing.thrice(), thing.four_times()), (9, 12));
+    assert_eq!(Thing::ANSWER, 42);
+    assert_eq!(thing.plain(), 4);
+    assert_eq!(thing.name(), "thing");
+
+    assert!(Some(1).describe());
+    assert_eq!(None::<u8>.describe_twice(), (false, false));
+}
@@ -0,0 +1,73 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! Name {
+    (() $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn name() -> &'static str {
+                stringify!($name)
+            }
+        }
+    };
+}
+
+macro_rules! Tag {
+    (($tag:expr) $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn tag() -> &'static str {
+                $tag
+            }
+        }
+    };
+}
+
+macro_rules! rename_to {
+    (($new_name:ident), then $cb:tt, $(#[$($attrs:tt)*])* struct $_old_name:ident $($tail:tt)*) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* struct $new_name $($tail)* }
+    };
+}
+
+macro_attr_bundle! { Labelled = Name!, Tag!("labelled"), }
+macro_attr_bundle! { Everything = Clone, Debug, Labelled! }
+
+macro_attr! {
+    #[derive(Debug, Labelled!)]
+    struct Derived;
+}
+
+macro_attr! {
+    #[Everything!]
+    #[derive(PartialEq)]
+    struct Attributed { value: i32 }
+}
+
+macro_attr! {
+    #[Everything!]
+    #[rename_to!(Renamed)]
+    struct Original;
+}
+
+#[test]
+fn test_bundles() {
+    assert_eq!(Derived::name(), "Derived");
+    assert_eq!(Derived::tag(), "labelled");
+    assert_eq!(format!("{:?}", Derived), "Derived");
+
+    let a = Attributed { value: 1 };
+    assert_eq!(a.clone(), a);
+    assert_eq!(format!("{:?}", a), "Attributed { value: 1 }");
+    assert_eq!(Attributed::name(), "Attributed");
+    assert_eq!(Attributed::tag(), "labelled");
+
+    assert_eq!(format!("{:?}", Renamed.clone()), "Renamed");
+    assert_eq!(Renamed::name(), "Renamed");
+    assert_eq!(Renamed::tag(), "labelled");
+}
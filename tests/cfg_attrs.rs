@@ -0,0 +1,129 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! Name {
+    (
+        ()
+        $(#[$($_attrs:tt)*])*
+        $vis:vis struct $name:ident $($_tail:tt)*
+    ) => {
+        impl $name {
+            pub fn name() -> &'static str {
+                stringify!($name)
+            }
+        }
+    };
+}
+
+macro_rules! Tag {
+    (
+        ($tag:expr)
+        $(#[$($_attrs:tt)*])*
+        $vis:vis struct $name:ident $($_tail:tt)*
+    ) => {
+        impl $name {
+            pub fn tag() -> &'static str {
+                $tag
+            }
+        }
+    };
+}
+
+macro_rules! rename_to {
+    (
+        ($new_name:ident),
+        then $cb:tt,
+        $(#[$($attrs:tt)*])*
+        $vis:vis struct $_old_name:ident $($tail:tt)*
+    ) => {
+        macro_attr_callback! {
+            $cb,
+            $(#[$($attrs)*])*
+            $vis struct $new_name $($tail)*
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Debug)]
+    #[cfg_attr(test, derive(Clone, Name!))]
+    #[cfg_attr(any(), derive(Tag!("never")))]
+    pub struct Enabled;
+}
+
+macro_attr! {
+    #[cfg_attr(test, derive(Tag!("tagged")), rename_to!(Renamed), derive(Debug))]
+    #[cfg_attr(not(test), rename_to!(NotRenamed))]
+    pub struct Original { pub value: i32 }
+}
+
+macro_attr! {
+    #[cfg_attr(not(test), derive(Name!))]
+    pub struct Disabled;
+
+    impl Disabled {
+        pub fn name() -> &'static str {
+            "not derived"
+        }
+    }
+}
+
+macro_attr! {
+    #[cfg(any())]
+    #[derive(Name!, Tag!("missing"))]
+    pub struct Missing;
+
+    #[cfg(test)]
+    #[cfg(not(any()))]
+    #[derive(Name!)]
+    pub struct Present;
+
+    #[cfg_attr(test, cfg(any()))]
+    #[derive(Name!)]
+    pub struct AlsoMissing;
+}
+
+macro_rules! Twice {
+    (() fn $name:ident () -> $ty:ty { $($_body:tt)* }) => {
+        pub fn twice() -> $ty { 2 * Self::$name() }
+    };
+}
+
+pub struct Numbers;
+
+macro_attr! {
+    impl Numbers {
+        #[cfg(any())]
+        #[derive(Twice!)]
+        fn once() -> i32 { 1 }
+
+        #[cfg(test)]
+        #[derive(Twice!)]
+        fn once() -> i32 { 21 }
+    }
+}
+
+#[test]
+fn test_cfg_attrs() {
+    assert_eq!(Enabled::name(), "Enabled");
+    assert_eq!(format!("{:?}", Enabled.clone()), "Enabled");
+
+    assert_eq!(Renamed::tag(), "tagged");
+    assert_eq!(format!("{:?}", Renamed { value: 1 }), "Renamed { value: 1 }");
+
+    assert_eq!(Disabled::name(), "not derived");
+}
+
+#[test]
+fn test_cfg_propagation() {
+    assert_eq!(Present::name(), "Present");
+    assert_eq!(Numbers::twice(), 42);
+}
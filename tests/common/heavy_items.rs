@@ -0,0 +1,100 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+/*
+The heavily attributed items shared by `benches/split_attrs.rs` and `tests/recursion_limit.rs`.  Each has a 40 line doc comment, nine built-in derivations and 24 macro derivations.  This file is pulled in with `include!`.
+*/
+trait T0 {}
+trait T1 {}
+trait T2 {}
+trait T3 {}
+trait T4 {}
+trait T5 {}
+trait T6 {}
+trait T7 {}
+trait T8 {}
+trait T9 {}
+trait T10 {}
+trait T11 {}
+trait T12 {}
+trait T13 {}
+trait T14 {}
+trait T15 {}
+trait T16 {}
+trait T17 {}
+trait T18 {}
+trait T19 {}
+trait T20 {}
+trait T21 {}
+trait T22 {}
+trait T23 {}
+
+macro_rules! Marker {
+    (($tr:ident) $_vis:vis struct $name:ident $($_tail:tt)*) => {
+        impl $tr for $name {}
+    };
+}
+
+macro_rules! heavy_items {
+    ($($name:ident)*) => {
+        $(
+            macro_attr! {
+                /// Line 1 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 2 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 3 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 4 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 5 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 6 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 7 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 8 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 9 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 10 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 11 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 12 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 13 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 14 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 15 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 16 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 17 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 18 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 19 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 20 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 21 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 22 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 23 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 24 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 25 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 26 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 27 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 28 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 29 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 30 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 31 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 32 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 33 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 34 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 35 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 36 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 37 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 38 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 39 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                /// Line 40 of a long doc comment, each line of which is a separate `#[doc]` attribute.
+                #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+                #[derive(
+                    Marker!(T0), Marker!(T1), Marker!(T2), Marker!(T3), Marker!(T4), Marker!(T5),
+                    Marker!(T6), Marker!(T7), Marker!(T8), Marker!(T9), Marker!(T10), Marker!(T11),
+                    Marker!(T12), Marker!(T13), Marker!(T14), Marker!(T15), Marker!(T16), Marker!(T17),
+                    Marker!(T18), Marker!(T19), Marker!(T20), Marker!(T21), Marker!(T22), Marker!(T23),
+                )]
+                #[allow(dead_code)]
+                pub struct $name;
+            }
+        )*
+    };
+}
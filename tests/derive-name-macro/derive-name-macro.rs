@@ -7,20 +7,54 @@ Licensed under the MIT license (see LICENSE or <http://opensource.org
 files in the project carrying such notice may not be copied, modified,
 or distributed except according to those terms.
 */
-#[macro_export]
-macro_rules! Name {
-    (
-        ()
-        struct $name:ident $($_tail:tt)*
-    ) => {
-        impl $name {
-            pub fn name() -> &'static str {
-                stringify!($name)
-            }
+/*!
+Provides the `Name` derivation and the `aka` attribute, both as procedural macros and as macros-by-example.  Only one of each can be exported under a given name, so this crate uses `macro_attr_if_proc_macros!` to export the ones `macro_attr!` will expect when it expands `Name~!` and `aka~!`.
+*/
+#[macro_use] extern crate macro_attr;
+extern crate derive_name_proc;
+
+macro_attr_if_proc_macros! {
+    proc_macros: {
+        pub use derive_name_proc::{Name, aka};
+    }
+    fallback: {
+        #[macro_export]
+        macro_rules! Name {
+            (
+                ()
+                struct $name:ident $($_tail:tt)*
+            ) => {
+                impl $name {
+                    pub fn name() -> &'static str {
+                        stringify!($name)
+                    }
+
+                    pub fn derived_by() -> &'static str {
+                        "macro-by-example"
+                    }
+                }
+            };
+        }
+
+        #[macro_export]
+        macro_rules! aka {
+            (
+                ($alias:ident), then $cb:tt,
+                $(#[$($attrs:tt)*])*
+                struct $name:ident $($tail:tt)*
+            ) => {
+                impl $name {
+                    pub fn aka() -> &'static str {
+                        stringify!($alias)
+                    }
+                }
 
-            pub fn derived_by() -> &'static str {
-                "macro-by-example"
-            }
+                macro_attr_callback! {
+                    $cb,
+                    $(#[$($attrs)*])*
+                    struct $name $($tail)*
+                }
+            };
         }
-    };
+    }
 }
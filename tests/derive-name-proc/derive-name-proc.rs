@@ -7,36 +7,52 @@ Licensed under the MIT license (see LICENSE or <http://opensource.org
 files in the project carrying such notice may not be copied, modified,
 or distributed except according to those terms.
 */
-#![feature(proc_macro, proc_macro_lib)]
-#![crate_type="proc-macro"]
-
-#[macro_use] extern crate quote;
-
 extern crate proc_macro;
-extern crate syn;
 
-use self::proc_macro::TokenStream;
+use proc_macro::{TokenStream, TokenTree};
 
 #[proc_macro_derive(Name)]
 pub fn name(input: TokenStream) -> TokenStream {
-    let input = input.to_string();
-    let ast = syn::parse_macro_input(&input).unwrap();
-    let name = &ast.ident;
-    let name_str = name.to_string();
+    let name = struct_name(input);
 
-    let out = quote! {
-        #ast
+    format!(r#"
+        impl {name} {{
+            pub fn name() -> &'static str {{
+                "{name}"
+            }}
 
-        impl #name {
-            pub fn name() -> &'static str {
-                #name_str
-            }
-
-            pub fn derived_by() -> &'static str {
+            pub fn derived_by() -> &'static str {{
                 "procedural macro"
-            }
-        }
-    };
+            }}
+        }}
+    "#, name = name).parse().unwrap()
+}
+
+#[proc_macro_attribute]
+pub fn aka(args: TokenStream, item: TokenStream) -> TokenStream {
+    let name = struct_name(item.clone());
+
+    let mut out = item;
+    out.extend(format!(r#"
+        impl {name} {{
+            pub fn aka() -> &'static str {{
+                "{alias}"
+            }}
+        }}
+    "#, name = name, alias = args).parse::<TokenStream>().unwrap());
+    out
+}
 
-    out.to_string().parse().unwrap()
+fn struct_name(input: TokenStream) -> String {
+    let mut tts = input.into_iter();
+    loop {
+        match tts.next() {
+            Some(TokenTree::Ident(ref ident)) if ident.to_string() == "struct" => match tts.next() {
+                Some(TokenTree::Ident(name)) => return name.to_string(),
+                _ => panic!("expected struct name"),
+            },
+            Some(_) => (),
+            None => panic!("expected a struct"),
+        }
+    }
 }
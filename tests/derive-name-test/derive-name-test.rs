@@ -7,16 +7,9 @@ Licensed under the MIT license (see LICENSE or <http://opensource.org
 files in the project carrying such notice may not be copied, modified,
 or distributed except according to those terms.
 */
-#![cfg_attr(feature="unstable-macros-1-1", feature(proc_macro))]
-
 #[macro_use] extern crate macro_attr;
-
-#[cfg(not(feature="unstable-macros-1-1"))]
 #[macro_use] extern crate derive_name_macro;
 
-#[cfg(feature="unstable-macros-1-1")]
-#[macro_use] extern crate derive_name_proc;
-
 macro_rules! remove_body {
     (
         (),
@@ -51,11 +44,23 @@ macro_attr! {
     #[derive(Debug, Name~!)]
     #[remove_body!]
     #[use_secret_alias!(Alucard)]
+    #[aka~!(Dracula)]
     struct Alucard;
 }
 
-fn main() {
+/*
+Both implementations are exercised by building this test with and without the `proc-macros` feature.
+*/
+#[test]
+fn test_derive_name() {
     assert_eq!(format!("{:?}", Alucard), "Alucard");
     assert_eq!(Alucard::name(), "Alucard");
-    println!("derive-name-test: Ok ({}).", Alucard::derived_by());
+    assert_eq!(Alucard::aka(), "Dracula");
+
+    let expected = if cfg!(feature = "proc-macros") {
+        "procedural macro"
+    } else {
+        "macro-by-example"
+    };
+    assert_eq!(Alucard::derived_by(), expected);
 }
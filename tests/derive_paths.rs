@@ -0,0 +1,73 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+#[macro_export]
+macro_rules! Name {
+    (() $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn name() -> &'static str {
+                stringify!($name)
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! Tag {
+    (($tag:expr) $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn tag() -> &'static str {
+                $tag
+            }
+        }
+    };
+}
+
+macro_rules! tagged_struct {
+    ($name:ident, $tag:expr) => {
+        macro_attr! {
+            #[derive(Debug, $crate::Name!, $crate::Tag!($tag))]
+            pub struct $name;
+        }
+    };
+}
+
+mod inner {
+    macro_attr! {
+        #[derive(Clone, ::Name!, ::Tag!("inner"))]
+        pub struct Inner;
+    }
+}
+
+macro_attr! {
+    #[derive(::std::fmt::Debug, std::clone::Clone, ::Name!)]
+    pub struct Absolute;
+}
+
+macro_attr_bundle! { Both = ::Name!, ::Tag!("bundled") }
+
+macro_attr! {
+    #[derive(Both!)]
+    pub struct Bundled;
+}
+
+tagged_struct!(Tagged, "tagged");
+
+#[test]
+fn test_derive_paths() {
+    assert_eq!(inner::Inner::name(), "Inner");
+    assert_eq!(inner::Inner::tag(), "inner");
+    assert_eq!(format!("{:?}", Absolute.clone()), "Absolute");
+    assert_eq!(Absolute::name(), "Absolute");
+    assert_eq!(Bundled::tag(), "bundled");
+    assert_eq!(Tagged::name(), "Tagged");
+    assert_eq!(Tagged::tag(), "tagged");
+}
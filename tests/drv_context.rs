@@ -0,0 +1,111 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! Name {
+    (() $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_rules! Context {
+    (
+        ()
+        { derive($($bi_drvs:tt)*), macros($($drvs:tt)*), attrs($($attrs:tt)*) }
+        $(pub)* struct $name:ident $($_tail:tt)*
+    ) => {
+        impl $name {
+            pub fn derives() -> &'static str { stringify!($($bi_drvs)*) }
+            pub fn macros() -> &'static str { stringify!($($drvs)*) }
+            pub fn attrs() -> &'static str { stringify!($($attrs)*) }
+        }
+    };
+}
+
+macro_rules! Duplicate {
+    (
+        ($method:ident)
+        { derive($($bi_drvs:tt)*), $($_ctx:tt)* }
+        $(pub)* struct $name:ident $($_tail:tt)*
+    ) => {
+        Duplicate! { @find_copy $name, $method, ($($bi_drvs)*) }
+    };
+
+    (@find_copy $name:ident, $method:ident, (Copy, $($_tail:tt)*)) => {
+        impl $name {
+            pub fn $method(self) -> (Self, Self) { (self, self) }
+        }
+    };
+
+    (@find_copy $name:ident, $method:ident, ($_drv:tt, $($tail:tt)*)) => {
+        Duplicate! { @find_copy $name, $method, ($($tail)*) }
+    };
+
+    (@find_copy $name:ident, $method:ident, ()) => {
+        impl $name {
+            pub fn $method(&self) -> (Self, Self) { (self.clone(), self.clone()) }
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Clone, Copy, Debug, Context@!, Name!, Duplicate@!(dup))]
+    #[repr(C)]
+    pub struct Point(i32, i32);
+}
+
+macro_attr! {
+    #[derive(Debug, Duplicate@!(dup), Clone)]
+    pub struct Label(String);
+}
+
+macro_attr! {
+    #[macro_attr_trace(UNIT_TRACE)]
+    #[derive(Name!, Context@!)]
+    struct Unit;
+}
+
+/// Strips whitespace, since how tokens are spaced out by `stringify!` varies between compiler versions.
+fn squash(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[test]
+fn test_drv_context() {
+    assert_eq!(Point::name(), "Point");
+    assert_eq!(squash(Point::derives()), "Clone,Copy,Debug,");
+    assert_eq!(squash(Point::macros()), "Context@!(),Name!(),Duplicate@!(dup),");
+    assert_eq!(squash(Point::attrs()), "#[repr(C)]");
+
+    assert_eq!(Unit::name(), "Unit");
+    assert_eq!(Unit::derives(), "");
+    assert_eq!(squash(Unit::macros()), "Name!(),Context@!(),");
+    assert_eq!(Unit::attrs(), "");
+    assert_eq!(squash(UNIT_TRACE), squash("
+        derivation: Name!()
+        input: () struct Unit;
+        derivation: Context@!()
+        input: () { derive(), macros(Name!(), Context@!(),), attrs() } struct Unit;
+        output: struct Unit;
+    "));
+}
+
+#[test]
+fn test_drv_context_adapt() {
+    let p = Point(1, 2);
+    let (a, b) = p.dup();
+    assert_eq!(format!("{:?} {:?} {:?}", p, a, b), "Point(1, 2) Point(1, 2) Point(1, 2)");
+
+    let l = Label("x".into());
+    let (a, b) = l.dup();
+    assert_eq!(format!("{:?} {:?} {:?}", l, a, b), "Label(\"x\") Label(\"x\") Label(\"x\")");
+}
@@ -0,0 +1,42 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! Name {
+    (() $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_rules! Getter {
+    (($method:ident, $value:expr) $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn $method(&self) -> i32 { $value }
+        }
+    };
+
+    (($method:ident, $value:expr) { $($_ctx:tt)* } $(pub)* struct $name:ident $($_tail:tt)*) => {
+        Getter! { ($method, $value) struct $name; }
+    };
+}
+
+macro_attr! {
+    #[derive(Clone, Name!, Getter!(one, 1))]
+    #[derive(Getter!(two, 2), Getter@!(three, 3), Debug)]
+    pub struct Numbers;
+}
+
+#[test]
+fn test_distinct_arguments() {
+    assert_eq!(Numbers::name(), "Numbers");
+    assert_eq!((Numbers.one(), Numbers.two(), Numbers.three()), (1, 2, 3));
+}
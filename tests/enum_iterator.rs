@@ -51,13 +51,13 @@ macro_rules! EnumIterator {
         @collect_variants ($name:ident),
         ($var:ident $_struct:tt, $($tail:tt)*) -> ($($var_names:tt)*)
     ) => {
-        const _error: () = concat!(
+        compile_error!(concat!(
             "cannot derive EnumIterator for ",
             stringify!($name),
             ", due to non-unitary variant ",
             stringify!($var),
             "."
-        );
+        ));
     };
 }
 
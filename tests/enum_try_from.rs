@@ -53,13 +53,13 @@ macro_rules! TryFrom {
         @collect_variants ($name:ident),
         ($var:ident $_struct:tt, $($tail:tt)*) -> ($($var_names:tt)*)
     ) => {
-        const _error: () = concat!(
+        compile_error!(concat!(
             "cannot derive TryFrom for ",
             stringify!($name),
             ", due to non-unitary variant ",
             stringify!($var),
             "."
-        );
+        ));
     };
 }
 
@@ -0,0 +1,162 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+/*
+Wraps the type of a field in a `Box`.
+*/
+macro_rules! boxed {
+    (
+        (), then $cb:tt,
+        $(#[$($attrs:tt)*])* $vis:vis $name:ident: $ty:ty, $($tail:tt)*
+    ) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* $vis $name: Box<$ty>, $($tail)* }
+    };
+
+    (
+        (), then $cb:tt,
+        $(#[$($attrs:tt)*])* $vis:vis $ty:ty, $($tail:tt)*
+    ) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* $vis Box<$ty>, $($tail)* }
+    };
+}
+
+/*
+Replaces the type of a field.
+*/
+macro_rules! retype {
+    (
+        ($new:ty), then $cb:tt,
+        $(#[$($attrs:tt)*])* $vis:vis $name:ident: $_ty:ty, $($tail:tt)*
+    ) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* $vis $name: $new, $($tail)* }
+    };
+}
+
+/*
+Removes a variant entirely.
+*/
+macro_rules! remove {
+    ((), then $cb:tt, $(#[$($_attrs:tt)*])* $_name:ident $(($($_tuple:tt)*))* $({$($_fields:tt)*})*, $($tail:tt)*) => {
+        macro_attr_callback! { $cb, $($tail)* }
+    };
+}
+
+/*
+Appends a method returning the field's name and the given tags.
+*/
+#[macro_export]
+macro_rules! tagged {
+    (
+        ($($tags:expr),*), then $cb:tt,
+        $(#[$($attrs:tt)*])* $vis:vis $name:ident: $ty:ty, $($tail:tt)*
+    ) => {
+        macro_attr_callback! { $cb, $(#[$($attrs)*])* $vis $name: $ty, $($tail)* }
+
+        impl Tagged {
+            fn tags() -> (&'static str, Vec<&'static str>) {
+                (stringify!($name), vec![$($tags),*])
+            }
+        }
+    };
+}
+
+/*
+Picks the last of two types, which are separated by an empty argument.
+*/
+macro_rules! pick {
+    ($_a:ty, , $b:ty) => { $b };
+}
+
+/*
+Lists the variants of an enum, so we can see what the derivation was given.
+*/
+macro_rules! VariantNames {
+    (() $(pub)* enum $name:ident { $($body:tt)* }) => {
+        macro_attr_parse_variants! { (VariantNames! { @variants $name, }), $($body)* }
+    };
+
+    (
+        @variants $name:ident,
+        variants: ($((attrs: $_attrs:tt, name: $vname:ident, kind: $_kind:ident, body: $_body:tt, discriminant: $_disc:tt),)*)
+    ) => {
+        impl $name {
+            fn variant_names() -> Vec<&'static str> {
+                vec![$(stringify!($vname)),*]
+            }
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Named {
+        #[boxed!]
+        /// Doc comments after a macro attribute are passed along.
+        pub a: i32,
+        b: i32,
+        #[retype!(u64)]
+        #[boxed!]
+        c: u8
+    }
+}
+
+macro_attr! {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Tuple(#[boxed!] pub i32, u8, #[boxed!] Vec<u8>,) where u8: Clone;
+}
+
+macro_attr! {
+    #[derive(Clone, Debug, PartialEq, VariantNames!)]
+    enum Shape {
+        Circle(#[boxed!] f32),
+        #[remove!]
+        Line(f32, f32),
+        Rect {
+            #[retype!((f32, f32))]
+            size: f32,
+            #[boxed!]
+            origin: (f32, f32),
+        },
+        #[remove!]
+        #[boxed!]
+        Point,
+    }
+}
+
+macro_attr! {
+    #[derive(Debug)]
+    struct Tagged {
+        a: pick!(u8, , u16),
+        #[::tagged!("one", "two")]
+        b: pick!(u8, , u32)
+    }
+}
+
+#[test]
+fn test_field_attrs() {
+    let named = Named { a: Box::new(1), b: 2, c: Box::new(3u64) };
+    assert_eq!(named.clone(), named);
+    assert_eq!(format!("{:?}", named), "Named { a: 1, b: 2, c: 3 }");
+
+    let tuple = Tuple(Box::new(1), 2, Box::new(vec![3]));
+    assert_eq!(format!("{:?}", tuple), "Tuple(1, 2, [3])");
+
+    let shapes = vec![Shape::Circle(Box::new(1.0)), Shape::Rect { size: (1.0, 2.0), origin: Box::new((0.0, 0.0)) }];
+    assert_eq!(shapes.clone(), shapes);
+    assert_eq!(Shape::variant_names(), vec!["Circle", "Rect"]);
+}
+
+#[test]
+fn test_field_attr_args() {
+    let tagged = Tagged { a: 1u16, b: 2u32 };
+    assert_eq!(format!("{:?}", tagged), "Tagged { a: 1, b: 2 }");
+    assert_eq!(Tagged::tags(), ("b", vec!["one", "two"]));
+}
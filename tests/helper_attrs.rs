@@ -0,0 +1,152 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+trait Labels {
+    fn labels() -> Vec<&'static str>;
+}
+
+macro_rules! label {
+    ($name:tt,) => { stringify!($name) };
+    ($name:tt, #[macro_attr(rename = $label:expr)] $($tail:tt)*) => { $label };
+    ($name:tt, #[$($_attr:tt)*] $($tail:tt)*) => { label!($name, $($tail)*) };
+}
+
+macro_rules! Labels {
+    (() $($item:tt)*) => {
+        macro_attr_parse_item! { (Labels! { @item }), $($item)* }
+    };
+
+    (
+        @item
+        attrs: $_attrs:tt, vis: $_vis:tt, kind: struct, name: $name:ident,
+        generics: $_generics:tt, impl_generics: ($($impl_gen:tt)*), ty_generics: ($($ty_gen:tt)*),
+        ty_params: $_ty_params:tt, where: ($($where_:tt)*), body: ({ $($body:tt)* })
+    ) => {
+        macro_attr_parse_named_fields! {
+            (Labels! { @named ($name<$($ty_gen)*>), ($($impl_gen)*), ($($where_)*), }),
+            $($body)*
+        }
+    };
+
+    (
+        @item
+        attrs: $_attrs:tt, vis: $_vis:tt, kind: struct, name: $name:ident,
+        generics: $_generics:tt, impl_generics: ($($impl_gen:tt)*), ty_generics: ($($ty_gen:tt)*),
+        ty_params: $_ty_params:tt, where: ($($where_:tt)*), body: (($($body:tt)*))
+    ) => {
+        macro_attr_parse_tuple_fields! {
+            (Labels! { @tuple ($name<$($ty_gen)*>), ($($impl_gen)*), ($($where_)*), }),
+            $($body)*
+        }
+    };
+
+    (
+        @item
+        attrs: $_attrs:tt, vis: $_vis:tt, kind: enum, name: $name:ident,
+        generics: $_generics:tt, impl_generics: ($($impl_gen:tt)*), ty_generics: ($($ty_gen:tt)*),
+        ty_params: $_ty_params:tt, where: ($($where_:tt)*), body: ({ $($body:tt)* })
+    ) => {
+        macro_attr_parse_variants! {
+            (Labels! { @variants ($name<$($ty_gen)*>), ($($impl_gen)*), ($($where_)*), }),
+            $($body)*
+        }
+    };
+
+    (
+        @named ($($ty:tt)*), ($($impl_gen:tt)*), ($($where_:tt)*),
+        fields: ($((attrs: ($($attrs:tt)*), vis: $_vis:tt, name: $fname:ident, ty: $_ty:ty),)*)
+    ) => {
+        Labels! { @impl ($($ty)*), ($($impl_gen)*), ($($where_)*), $(label!($fname, $($attrs)*),)* }
+    };
+
+    (
+        @tuple ($($ty:tt)*), ($($impl_gen:tt)*), ($($where_:tt)*),
+        fields: ($((attrs: ($($attrs:tt)*), vis: $_vis:tt, index: $idx:tt, ty: $_ty:ty),)*)
+    ) => {
+        Labels! { @impl ($($ty)*), ($($impl_gen)*), ($($where_)*), $(label!($idx, $($attrs)*),)* }
+    };
+
+    (
+        @variants ($($ty:tt)*), ($($impl_gen:tt)*), ($($where_:tt)*),
+        variants: ($((
+            attrs: ($($attrs:tt)*), name: $vname:ident, kind: $_kind:ident, body: $_body:tt,
+            discriminant: $_disc:tt
+        ),)*)
+    ) => {
+        Labels! { @impl ($($ty)*), ($($impl_gen)*), ($($where_)*), $(label!($vname, $($attrs)*),)* }
+    };
+
+    (@impl ($($ty:tt)*), ($($impl_gen:tt)*), ($($where_:tt)*), $($labels:expr,)*) => {
+        impl<$($impl_gen)*> Labels for $($ty)* where $($where_)* {
+            fn labels() -> Vec<&'static str> {
+                vec![$($labels),*]
+            }
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Clone, Debug, PartialEq, Labels!)]
+    struct Named<T> where T: Clone {
+        /// Helpers can be mixed with other attributes.
+        #[macro_attr(rename = "first")]
+        #[allow(dead_code)]
+        a: T,
+        b: Vec<(u8, u8)>,
+        #[macro_attr(rename = "third")]
+        pub c: i32,
+    }
+}
+
+macro_attr! {
+    #[derive(Debug, Labels!)]
+    struct Tuple(#[macro_attr(rename = "zero")] pub u8, i32) where i32: Copy;
+}
+
+macro_attr! {
+    #[derive(Clone, Copy, Debug, PartialEq, Labels!)]
+    enum Mixed {
+        #[macro_attr(rename = "unit")]
+        Unit,
+        Tuple(#[macro_attr(rename = "ignored")] u8),
+        #[doc = "Documented."]
+        #[macro_attr(rename = "struct")]
+        Struct { #[macro_attr(rename = "ignored")] x: i32 },
+        Plain,
+    }
+}
+
+macro_attr! {
+    #[derive(Clone, Copy, Debug, Labels!)]
+    enum Discriminants {
+        Zero,
+        #[macro_attr(rename = "answer")]
+        Answer = 42,
+    }
+}
+
+#[test]
+fn test_helper_attrs() {
+    let named = Named { a: 1u8, b: vec![], c: 3 };
+    assert_eq!(named.clone(), named);
+    assert_eq!(<Named<u8> as Labels>::labels(), vec!["first", "b", "third"]);
+
+    let tuple = Tuple(0, 1);
+    assert_eq!(format!("{:?}", tuple), "Tuple(0, 1)");
+    assert_eq!(Tuple::labels(), vec!["zero", "1"]);
+
+    assert_eq!(Mixed::Struct { x: 0 }, Mixed::Struct { x: 0 });
+    assert_eq!(format!("{:?}", Mixed::Tuple(1)), "Tuple(1)");
+    assert_eq!(Mixed::labels(), vec!["unit", "Tuple", "struct", "Plain"]);
+
+    assert_eq!(Discriminants::Answer as isize, 42);
+    assert_eq!(Discriminants::labels(), vec!["Zero", "answer"]);
+}
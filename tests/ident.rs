@@ -0,0 +1,88 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+/*
+Defines an iterator type named after the enum, rather than asking for a name.
+*/
+macro_rules! Variants {
+    (() $(pub)* enum $name:ident { $($var:ident),* $(,)* }) => {
+        macro_attr_ident! { (Variants! { @iter $name, }), camel($name, variants), ($($var)*) }
+    };
+
+    (@iter $name:ident, $iter:ident, ($($var:ident)*)) => {
+        pub struct $iter(&'static [$name]);
+
+        impl Iterator for $iter {
+            type Item = $name;
+
+            fn next(&mut self) -> Option<$name> {
+                match self.0.split_first() {
+                    Some((&first, rest)) => { self.0 = rest; Some(first) },
+                    None => None,
+                }
+            }
+        }
+
+        impl $name {
+            pub fn variants() -> $iter {
+                $iter(&[$($name::$var),*])
+            }
+        }
+    };
+}
+
+macro_rules! Names {
+    ($_args:tt $(pub)* enum $name:ident $_body:tt) => {
+        macro_attr_ident! { (Names! { @fn }), concat(candy, "_", 2, r#type) }
+        macro_attr_ident! { (::Names_path! { @fn }), snake(get, $name, "HTTPServer", 42) }
+        macro_attr_ident! { (Names! { @const }), upper_snake(lowerCamel, XMLHttp), 7 }
+    };
+
+    (@fn $fn_name:ident) => {
+        pub fn $fn_name() -> &'static str { stringify!($fn_name) }
+    };
+
+    (@const $const_name:ident, $value:expr) => {
+        pub const $const_name: i32 = $value;
+    };
+}
+
+#[macro_export]
+macro_rules! Names_path {
+    (@fn $fn_name:ident) => {
+        pub fn $fn_name() -> &'static str { stringify!($fn_name) }
+    };
+}
+
+macro_rules! forward_expr {
+    ($e:expr) => {
+        macro_attr_ident! { (Names! { @fn }), concat(forwarded_, $e) }
+    };
+}
+
+macro_attr! {
+    #[derive(Copy, Clone, Debug, PartialEq, Variants!, Names!)]
+    pub enum Candy { Musk, FruitRock, BoPeeps }
+}
+
+forward_expr!(1);
+
+#[test]
+fn test_ident() {
+    let all: Vec<Candy> = Candy::variants().collect();
+    assert_eq!(all, vec![Candy::Musk, Candy::FruitRock, Candy::BoPeeps]);
+    let _: CandyVariants = Candy::variants();
+
+    assert_eq!(candy_2type(), "candy_2type");
+    assert_eq!(get_candy_http_server_42(), "get_candy_http_server_42");
+    assert_eq!(LOWER_CAMEL_XML_HTTP, 7);
+    assert_eq!(forwarded_1(), "forwarded_1");
+}
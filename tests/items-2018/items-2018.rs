@@ -0,0 +1,53 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+/*!
+Items which only exist in the 2018 edition.  This is kept out of the workspace, since older versions of Cargo cannot parse its manifest.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! Source {
+    (($name:ident) $($item:tt)*) => {
+        const $name: &'static str = stringify!($($item)*);
+    };
+}
+
+macro_rules! tagged {
+    (($name:ident), then $cb:tt, $($item:tt)*) => {
+        const $name: bool = true;
+        macro_attr_callback! { $cb, $($item)* }
+    };
+}
+
+macro_attr! {
+    async fn dummy_async_fn() -> u8 { 1 }
+
+    #[derive(Source!(ASYNC_FN_SRC))]
+    #[tagged!(ASYNC_FN_TAG)]
+    pub async fn tagged_async_fn() -> u8 { 2 }
+
+    #[derive(Source!(ASYNC_UNSAFE_FN_SRC))]
+    #[tagged!(ASYNC_UNSAFE_FN_TAG)]
+    pub(crate) async unsafe fn tagged_async_unsafe_fn() -> u8 { 3 }
+}
+
+fn squash(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[test]
+fn test_async_items() {
+    let _ = dummy_async_fn();
+    let _ = tagged_async_fn();
+    let _ = unsafe { tagged_async_unsafe_fn() };
+
+    assert!(ASYNC_FN_TAG && ASYNC_UNSAFE_FN_TAG);
+    assert_eq!(squash(ASYNC_FN_SRC), "pubasyncfntagged_async_fn()->u8{2}");
+    assert_eq!(squash(ASYNC_UNSAFE_FN_SRC), "pub(crate)asyncunsafefntagged_async_unsafe_fn()->u8{3}");
+}
@@ -53,15 +53,97 @@ macro_attr! {
     use self::dummy_fn as dummy_use;
 }
 
-#[cfg(never)]
-mod super_dummy {
-    macro_attr! {
-        extern crate dummy_crate;
+macro_attr! {
+    union DummyUnion { a: u32, b: f32 }
+}
+
+macro_attr! {
+    unsafe impl Sync for DummyStruct {}
+}
+
+macro_attr! {
+    unsafe fn dummy_unsafe_fn() {}
+}
+
+macro_attr! {
+    const fn dummy_const_fn() -> u8 { 42 }
+}
+
+macro_attr! {
+    extern "C" fn dummy_extern_fn() {}
+}
+
+macro_attr! {
+    extern "C" {
+        fn abs(x: i32) -> i32;
     }
+}
+
+macro_attr! {
+    macro_rules! dummy_macro { () => { 42 }; }
+}
+
+macro_attr! {
+    #[allow(unused_extern_crates)]
+    extern crate core as dummy_crate;
+}
+
+/*
+A derivation and a macro attribute that accept any kind of item.
+*/
+macro_rules! Source {
+    (($name:ident) $($item:tt)*) => {
+        const $name: &'static str = stringify!($($item)*);
+    };
+}
+
+macro_rules! tagged {
+    (($name:ident), then $cb:tt, $($item:tt)*) => {
+        const $name: bool = true;
+        macro_attr_callback! { $cb, $($item)* }
+    };
+}
+
+macro_attr! {
+    #[derive(Source!(UNION_SRC))]
+    #[tagged!(UNION_TAG)]
+    #[derive(Clone, Copy)]
+    union TaggedUnion { a: u32, b: f32 }
+
+    #[derive(Source!(UNSAFE_IMPL_SRC))]
+    #[tagged!(UNSAFE_IMPL_TAG)]
+    unsafe impl Send for TaggedUnion {}
+
+    #[derive(Source!(UNSAFE_FN_SRC))]
+    #[tagged!(UNSAFE_FN_TAG)]
+    unsafe fn tagged_unsafe_fn() -> u8 { 1 }
 
-    macro_attr! {
-        extern "C" dummy_extern();
+    #[derive(Source!(CONST_FN_SRC))]
+    #[tagged!(CONST_FN_TAG)]
+    pub(crate) const fn tagged_const_fn() -> u8 { 2 }
+
+    #[derive(Source!(EXTERN_BLOCK_SRC))]
+    #[tagged!(EXTERN_BLOCK_TAG)]
+    extern "C" {
+        fn labs(x: i64) -> i64;
     }
+
+    #[derive(Source!(MACRO_RULES_SRC))]
+    #[tagged!(MACRO_RULES_TAG)]
+    macro_rules! tagged_macro { () => { 3 }; }
+
+    #[derive(Source!(EXTERN_CRATE_SRC))]
+    #[tagged!(EXTERN_CRATE_TAG)]
+    #[allow(unused_extern_crates)]
+    extern crate core as tagged_crate;
+
+    #[derive(Source!(FN_SRC))]
+    #[tagged!(FN_TAG)]
+    fn tagged_fn() -> u8 { 4 }
+}
+
+fn squash(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
 }
 
 #[test]
@@ -76,4 +158,36 @@ fn test_items() {
     let _: DummyType = ();
     let _: () = DUMMY_STATIC;
     let _: () = dummy_use();
+    let _: DummyUnion = DummyUnion { a: 1 };
+    let _: () = unsafe { dummy_unsafe_fn() };
+    let _: u8 = dummy_const_fn();
+    let _: () = dummy_extern_fn();
+    let _: i32 = unsafe { abs(-3) };
+    let _: i32 = dummy_macro!();
+    let _: Option<dummy_crate::cell::Cell<u8>> = None;
+}
+
+#[test]
+fn test_tagged_items() {
+    let tags = [UNION_TAG, UNSAFE_IMPL_TAG, UNSAFE_FN_TAG, CONST_FN_TAG,
+        EXTERN_BLOCK_TAG, MACRO_RULES_TAG, EXTERN_CRATE_TAG, FN_TAG];
+    assert_eq!(tags, [true; 8]);
+
+    assert_eq!(squash(UNION_SRC), "unionTaggedUnion{a:u32,b:f32}");
+    assert_eq!(squash(UNSAFE_IMPL_SRC), "unsafeimplSendforTaggedUnion{}");
+    assert_eq!(squash(UNSAFE_FN_SRC), "unsafefntagged_unsafe_fn()->u8{1}");
+    assert_eq!(squash(CONST_FN_SRC), "pub(crate)constfntagged_const_fn()->u8{2}");
+    assert_eq!(squash(EXTERN_BLOCK_SRC), "extern\"C\"{fnlabs(x:i64)->i64;}");
+    assert_eq!(squash(MACRO_RULES_SRC), "macro_rules!tagged_macro{()=>{3};}");
+    assert_eq!(squash(EXTERN_CRATE_SRC), "externcratecoreastagged_crate;");
+    assert_eq!(squash(FN_SRC), "fntagged_fn()->u8{4}");
+
+    fn is_copy<T: Clone + Copy>(_: T) {}
+    is_copy(TaggedUnion { b: 1.0 });
+    assert_eq!(unsafe { tagged_unsafe_fn() }, 1);
+    assert_eq!(tagged_const_fn(), 2);
+    assert_eq!(unsafe { labs(-4) }, 4);
+    assert_eq!(tagged_macro!(), 3);
+    let _: Option<tagged_crate::cell::Cell<u8>> = None;
+    assert_eq!(tagged_fn(), 4);
 }
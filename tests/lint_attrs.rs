@@ -0,0 +1,66 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#![deny(warnings)]
+#[macro_use] extern crate macro_attr;
+
+pub trait Named {
+    fn name() -> &'static str;
+}
+
+macro_rules! Name {
+    (() $ctx:tt $(pub)* struct $name:ident $($_tail:tt)*) => {
+        macro_attr_inherit! {
+            $ctx,
+            impl Named for $name {
+                fn name() -> &'static str { stringify!($name) }
+            }
+        }
+    };
+}
+
+macro_rules! Unused {
+    (() $ctx:tt $(pub)* struct $name:ident $($_tail:tt)*) => {
+        macro_attr_inherit! {
+            $ctx,
+            impl $name {
+                fn unused() {}
+            }
+        }
+    };
+}
+
+macro_attr! {
+    #[deprecated(since = "0.2.0", note = "use `Current` instead")]
+    #[derive(Debug, Name@!)]
+    pub struct Old;
+}
+
+macro_attr! {
+    #[allow(dead_code)]
+    #[derive(Name@!, Unused@!)]
+    pub struct Current;
+}
+
+macro_attr! {
+    /// Both at once.
+    #[deprecated]
+    #[allow(dead_code)]
+    #[derive(Name@!, Unused@!)]
+    pub struct Both;
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_lint_attrs() {
+    assert_eq!(format!("{:?}", Old), "Old");
+    assert_eq!(Old::name(), "Old");
+    assert_eq!(Current::name(), "Current");
+    assert_eq!(Both::name(), "Both");
+}
@@ -0,0 +1,120 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! Name {
+    (
+        ()
+        $(#[$($_attrs:tt)*])*
+        struct $name:ident $($_tail:tt)*
+    ) => {
+        impl $name {
+            pub fn name() -> &'static str {
+                stringify!($name)
+            }
+        }
+    };
+}
+
+macro_rules! rename_to {
+    (
+        ($new_name:ident),
+        then $cb:tt,
+        $(#[$($attrs:tt)*])*
+        struct $_old_name:ident $($tail:tt)*
+    ) => {
+        macro_attr_callback! {
+            $cb,
+            $(#[$($attrs)*])*
+            struct $new_name $($tail)*
+        }
+    };
+}
+
+/*
+Macro attributes written as `#[name@!]` are given the pending derivations as a leading `#[derive(..)]`, and must pass them on (edited or not) the same way.
+*/
+macro_rules! without_macros {
+    ((), then $cb:tt, #[derive($($drvs:tt)*)] $($it:tt)*) => {
+        without_macros! { @drvs $cb, ($($drvs)*), (), ($($it)*) }
+    };
+
+    (@drvs $cb:tt, ($_drv:ident ! $(($($_args:tt)*))*, $($tail:tt)*), $kept:tt, $it:tt) => {
+        without_macros! { @drvs $cb, ($($tail)*), $kept, $it }
+    };
+
+    (@drvs $cb:tt, ($drv:ident, $($tail:tt)*), ($($kept:tt)*), $it:tt) => {
+        without_macros! { @drvs $cb, ($($tail)*), ($($kept)* $drv,), $it }
+    };
+
+    (@drvs $cb:tt, (), ($($kept:tt)*), ($($it:tt)*)) => {
+        macro_attr_callback! { $cb, #[derive($($kept)*)] $($it)* }
+    };
+}
+
+macro_rules! with_name {
+    ((), then $cb:tt, #[derive($($drvs:tt)*)] $($it:tt)*) => {
+        macro_attr_callback! { $cb, #[derive($($drvs)* Name!)] $($it)* }
+    };
+}
+
+macro_attr! {
+    #[derive(Clone, Name!, Debug)]
+    #[cfg(all())]
+    #[without_macros@!]
+    #[derive(Copy)]
+    struct Anonymous;
+}
+
+macro_attr! {
+    #[derive(Debug)]
+    #[with_name@!()]
+    #[rename_to!(Announced)]
+    struct Unannounced;
+}
+
+macro_attr! {
+    #[with_name@!]
+    struct Empty;
+}
+
+macro_attr! {
+    #[macro_attr_trace(EDITED_TRACE)]
+    #[derive(Clone)]
+    #[with_name@!]
+    struct Edited;
+}
+
+/// Strips whitespace, since how tokens are spaced out by `stringify!` varies between compiler versions.
+fn squash(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[test]
+fn test_mac_attr_derives() {
+    let a = Anonymous;
+    let _ = (a, a);
+    assert_eq!(format!("{:?}", a), "Anonymous");
+    assert_eq!(format!("{:?}", Announced), "Announced");
+    assert_eq!(Announced::name(), "Announced");
+    assert_eq!(Empty::name(), "Empty");
+}
+
+#[test]
+fn test_mac_attr_derives_trace() {
+    assert_eq!(Edited::name(), "Edited");
+    assert_eq!(squash(EDITED_TRACE), squash("
+        macro attribute: #[with_name@!()]
+        input: #[derive(Clone,)] struct Edited;
+        derivation: Name!()
+        input: () struct Edited;
+        output: #[derive(Clone,)] struct Edited;
+    "));
+}
@@ -52,6 +52,42 @@ macro_rules! use_secret_alias {
     };
 }
 
+/*
+The same as `use_secret_alias!`, but exported, so that callbacks can name it by path.
+*/
+#[macro_export]
+macro_rules! use_alias {
+    (
+        ($name:ident),
+        then $cb:tt,
+        $(#[$($attrs:tt)*])*
+        struct $_old_name:ident $($tail:tt)*
+    ) => {
+        macro_attr_callback! {
+            $cb,
+            $(#[$($attrs)*])*
+            struct $name $($tail)*
+        }
+    };
+}
+
+macro_rules! disguise {
+    (
+        ($name:ident),
+        then $cb:tt,
+        $($item:tt)*
+    ) => {
+        macro_attr_callback! {
+            (
+                remove_body! { (), },
+                $crate::use_alias! { ($name), },
+                $cb
+            ),
+            $($item)*
+        }
+    };
+}
+
 macro_attr! {
     #[derive(Debug, Name!)]
     #[remove_body!]
@@ -61,8 +97,36 @@ macro_attr! {
     }
 }
 
+macro_attr! {
+    #[derive(Debug, Name!)]
+    #[disguise!(Nosferatu)]
+    struct Orlok {
+        pub vulnerabilities: Vec<Vulnerability>,
+    }
+}
+
+macro_attr_callback! {
+    (
+        ::use_alias! { (Lestat), },
+        remove_body! { (), },
+        (macro_attr! {})
+    ),
+    #[derive(Debug, Name!)]
+    struct Vampire {
+        pub vulnerabilities: Vec<Vulnerability>,
+    }
+}
+
 #[test]
 fn test_mac_attrs() {
     assert_eq!(format!("{:?}", Alucard), "Alucard");
     assert_eq!(Alucard::name(), "Alucard");
 }
+
+#[test]
+fn test_mac_attr_pipelines() {
+    assert_eq!(format!("{:?}", Nosferatu), "Nosferatu");
+    assert_eq!(Nosferatu::name(), "Nosferatu");
+    assert_eq!(format!("{:?}", Lestat), "Lestat");
+    assert_eq!(Lestat::name(), "Lestat");
+}
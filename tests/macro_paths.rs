@@ -0,0 +1,97 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+/*
+None of the macros from `macro_attr` are imported here; they should work when named by path alone.
+*/
+extern crate macro_attr;
+
+macro_rules! Name {
+    (() $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn name() -> &'static str {
+                stringify!($name)
+            }
+        }
+    };
+
+    (() pub fn $name:ident $($_tail:tt)*) => {
+        pub fn method_name() -> &'static str {
+            stringify!($name)
+        }
+    };
+}
+
+macro_rules! Builder {
+    (($builder:ident) $ctx:tt $(pub)* struct $name:ident $($_tail:tt)*) => {
+        ::macro_attr::macro_attr_nested! {
+            $ctx,
+            #[derive(Default, Name!)]
+            pub struct $builder;
+        }
+    };
+}
+
+macro_rules! Describe {
+    (() $ctx:tt $(pub)* struct $name:ident $($_tail:tt)*) => {
+        ::macro_attr::macro_attr_inherit! {
+            $ctx,
+            impl $name {
+                pub fn describe(&self) -> String {
+                    format!("{:?}", self)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! rename_to {
+    (($new:ident), then $cb:tt, $(#[$($attrs:tt)*])* pub struct $_old:ident $($tail:tt)*) => {
+        ::macro_attr::macro_attr_callback! { $cb, $(#[$($attrs)*])* pub struct $new $($tail)* }
+    };
+}
+
+::macro_attr::macro_attr_bundle! { Named = Name! }
+
+::macro_attr::macro_attr_bundle! { Cloned = Clone, Debug }
+
+::macro_attr::macro_attr! {
+    #[derive(Named!)]
+    pub struct Bundled;
+}
+
+::macro_attr::macro_attr! {
+    #[Cloned!]
+    #[derive(Name!, Builder@!(RenamedBuilder), Describe@!)]
+    #[rename_to!(Renamed)]
+    pub struct Unnamed {
+        pub value: u8,
+    }
+}
+
+macro_attr::macro_attr! {
+    impl Renamed {
+        #[derive(Name!)]
+        pub fn value(&self) -> u8 {
+            self.value
+        }
+    }
+}
+
+#[test]
+fn test_macro_paths() {
+    let renamed = Renamed { value: 1 };
+    assert_eq!(Renamed::name(), "Renamed");
+    assert_eq!(Renamed::method_name(), "value");
+    assert_eq!(renamed.clone().value(), 1);
+    assert_eq!(renamed.describe(), "Renamed { value: 1 }");
+    assert_eq!(RenamedBuilder::name(), "RenamedBuilder");
+    assert_eq!(Bundled::name(), "Bundled");
+    let _: RenamedBuilder = Default::default();
+}
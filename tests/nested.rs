@@ -0,0 +1,135 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! Name {
+    (() $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+/*
+Items at the top level get the usual context; nested items have an extra `depth` entry.
+*/
+macro_rules! Depth {
+    (() { derive $_bi_drvs:tt, macros $_drvs:tt, attrs $_attrs:tt } $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn depth() -> usize { 0 }
+            pub fn context() -> &'static str { "" }
+        }
+    };
+
+    (() { derive $_bi_drvs:tt, macros $_drvs:tt, attrs $_attrs:tt, depth($($depth:tt)*) } $(pub)* struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            pub fn depth() -> usize { 0 $(+ macro_attr_count!($depth))* }
+            pub fn context() -> &'static str { stringify!(depth($($depth)*)) }
+        }
+    };
+}
+
+macro_rules! macro_attr_count {
+    (+) => { 1 };
+}
+
+/*
+A derivation which emits a helper type, which has derivations of its own.
+*/
+macro_rules! Builder {
+    (($builder:ident) $ctx:tt $(pub)* struct $name:ident $($_tail:tt)*) => {
+        macro_attr_nested! {
+            $ctx,
+            #[derive(Default, Name!, Depth@!)]
+            pub struct $builder;
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Name!, Depth@!, Builder@!(PointBuilder))]
+    pub struct Point(i32, i32);
+}
+
+/*
+Nesting through two derivations.
+*/
+macro_rules! Outer {
+    (($inner:ident, $innermost:ident) $ctx:tt $(pub)* struct $name:ident $($_tail:tt)*) => {
+        macro_attr_nested! {
+            $ctx,
+            #[derive(Depth@!, Builder@!($innermost))]
+            struct $inner;
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Outer@!(Inner, Innermost))]
+    struct Top;
+}
+
+/*
+A macro attribute which emits a companion type, which itself has a macro attribute that does the same.
+*/
+macro_rules! companion {
+    (($companion:ident $(, $more:ident)*), then $cb:tt, $($it:tt)*) => {
+        macro_attr_nested! {
+            $cb,
+            #[companion!($($more),*)]
+            #[derive(Name!, Depth@!)]
+            pub struct $companion;
+        }
+        macro_attr_callback! { $cb, $($it)* }
+    };
+
+    ((), then $cb:tt, $($it:tt)*) => {
+        macro_attr_callback! { $cb, $($it)* }
+    };
+}
+
+macro_attr! {
+    #[derive(Debug)]
+    #[companion!(First, Second)]
+    #[derive(Depth@!)]
+    pub struct Original;
+}
+
+#[test]
+fn test_nested_derivations() {
+    assert_eq!(Point::name(), "Point");
+    assert_eq!(Point::depth(), 0);
+    assert_eq!(PointBuilder::name(), "PointBuilder");
+    assert_eq!(PointBuilder::depth(), 1);
+    let _: PointBuilder = Default::default();
+
+    assert_eq!(Inner::depth(), 1);
+    assert_eq!(Innermost::name(), "Innermost");
+    assert_eq!(Innermost::depth(), 2);
+
+    assert_eq!(Point::context(), "");
+    assert_eq!(PointBuilder::context(), "depth(+)");
+    assert_eq!(Inner::context(), "depth(+)");
+    assert_eq!(Innermost::context(), "depth(+ +)");
+}
+
+#[test]
+fn test_nested_mac_attrs() {
+    assert_eq!(format!("{:?}", Original), "Original");
+    assert_eq!(Original::depth(), 0);
+    assert_eq!(First::name(), "First");
+    assert_eq!(First::depth(), 1);
+    assert_eq!(Second::name(), "Second");
+    assert_eq!(Second::depth(), 2);
+    assert_eq!(Original::context(), "");
+    assert_eq!(First::context(), "depth(+)");
+    assert_eq!(Second::context(), "depth(+ +)");
+    let _ = Top;
+}
@@ -0,0 +1,85 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! Name {
+    (() $vis:vis struct $name:ident $($_tail:tt)*) => { Name! { @impl $name } };
+    (() $vis:vis enum $name:ident $($_tail:tt)*) => { Name! { @impl $name } };
+
+    (@impl $name:ident) => {
+        impl $name {
+            pub fn name() -> &'static str { stringify!($name) }
+        }
+    };
+}
+
+macro_rules! rename_to {
+    (
+        ($new_name:ident),
+        then $cb:tt,
+        $(#[$($attrs:tt)*])*
+        $vis:vis struct $_old_name:ident $($tail:tt)*
+    ) => {
+        macro_attr_callback! {
+            $cb,
+            $(#[$($attrs)*])*
+            $vis struct $new_name $($tail)*
+        }
+    };
+}
+
+macro_rules! make_fn {
+    ($name:ident) => { pub fn $name() -> u8 { 7 } };
+}
+
+macro_attr! {
+    #[derive(Clone, Name!)]
+    pub struct First;
+
+    #[derive(Name!)]
+    pub enum Second { A, B }
+
+    const PAIR: (u8, First) = (1, First {});
+
+    use self::outer::inner::{Renamed, Deep};
+
+    mod outer {
+        //! Inner attributes are kept at the start of the module.
+        #![allow(dead_code)]
+
+        pub fn f() -> u8 { 1 }
+
+        pub mod inner {
+            #[derive(Debug, Name!)]
+            #[rename_to!(Renamed)]
+            pub struct Original(pub u8);
+
+            #[derive(Name!)]
+            pub struct Deep { pub field: u8 }
+
+            make_fn! { made }
+            make_fn!(also_made);
+        }
+    }
+}
+
+#[test]
+fn test_nested_items() {
+    assert_eq!(First::name(), "First");
+    assert_eq!(Second::name(), "Second");
+    let _ = (Second::A, Second::B, PAIR.1.clone());
+    assert_eq!(PAIR.0, 1);
+    assert_eq!(outer::f(), 1);
+    assert_eq!(Renamed::name(), "Renamed");
+    assert_eq!(format!("{:?}", Renamed(2)), "Renamed(2)");
+    assert_eq!(Deep::name(), "Deep");
+    assert_eq!(Deep { field: 3 }.field, 3);
+    assert_eq!(outer::inner::made() + outer::inner::also_made(), 14);
+}
@@ -0,0 +1,112 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! args_fn {
+    (
+        $fn_name:ident
+        positional: ($(($($pos:tt)*),)*),
+        keyed: ($((name: $key:ident, value: ($($value:tt)*)),)*)
+    ) => {
+        fn $fn_name() -> (Vec<String>, Vec<(&'static str, String)>) {
+            (
+                vec![$(squash(stringify!($($pos)*))),*],
+                vec![$((stringify!($key), squash(stringify!($($value)*)))),*],
+            )
+        }
+    };
+}
+
+fn squash(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+macro_attr_parse_args! { (args_fn! { no_args }), }
+macro_attr_parse_args! { (args_fn! { positional }), pub as_display -> &Display, i32, }
+macro_attr_parse_args! { (args_fn! { keyed }), a, b = (1, 2), flag, c = Vec<u8> }
+macro_attr_parse_args! { (args_fn! { only_keyed }), x = 1, y }
+macro_attr_parse_args! { (args_fn! { generic }), HashMap<K, V>, key = HashMap<K, Vec<Vec<V>>>, n = (a < b), last }
+
+#[test]
+fn test_parse_args() {
+    fn s(s: &str) -> String { s.into() }
+
+    assert_eq!(no_args(), (vec![], vec![]));
+    assert_eq!(positional(), (vec![s("pubas_display->&Display"), s("i32")], vec![]));
+    assert_eq!(keyed(), (vec![s("a")], vec![("b", s("(1,2)")), ("flag", s("true")), ("c", s("Vec<u8>"))]));
+    assert_eq!(only_keyed(), (vec![], vec![("x", s("1")), ("y", s("true"))]));
+    assert_eq!(generic(), (
+        vec![s("HashMap<K,V>")],
+        vec![("key", s("HashMap<K,Vec<Vec<V>>>")), ("n", s("(a<b)")), ("last", s("true"))],
+    ));
+}
+
+trait Greeting {
+    fn greeting() -> String;
+}
+
+macro_rules! Greeting {
+    (($($args:tt)*) $($item:tt)*) => {
+        macro_attr_parse_args! { (Greeting! { @args ($($item)*) }), $($args)* }
+    };
+
+    (@args $item:tt positional: (), keyed: $keyed:tt) => {
+        Greeting! { @keyed $item, ("Hello", false), $keyed }
+    };
+
+    (@args $item:tt positional: (($word:expr),), keyed: $keyed:tt) => {
+        Greeting! { @keyed $item, ($word, false), $keyed }
+    };
+
+    (@keyed $item:tt, ($_word:expr, $shout:expr), ((name: word, value: ($word:expr)), $($tail:tt)*)) => {
+        Greeting! { @keyed $item, ($word, $shout), ($($tail)*) }
+    };
+
+    (@keyed $item:tt, ($word:expr, $_shout:expr), ((name: shout, value: ($shout:expr)), $($tail:tt)*)) => {
+        Greeting! { @keyed $item, ($word, $shout), ($($tail)*) }
+    };
+
+    (@keyed (struct $name:ident;), ($word:expr, $shout:expr), ()) => {
+        impl Greeting for $name {
+            fn greeting() -> String {
+                let s = format!("{}, {}!", $word, stringify!($name));
+                if $shout { s.to_uppercase() } else { s }
+            }
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Greeting!)]
+    struct Alice;
+}
+
+macro_attr! {
+    #[derive(Greeting!("Hi"))]
+    struct Bob;
+}
+
+macro_attr! {
+    #[derive(Greeting!(shout = true, word = "Hey"))]
+    struct Carol;
+}
+
+macro_attr! {
+    #[derive(Greeting!("Yo", word = "Oi", shout))]
+    struct Dave;
+}
+
+#[test]
+fn test_parse_args_derive() {
+    assert_eq!(Alice::greeting(), "Hello, Alice!");
+    assert_eq!(Bob::greeting(), "Hi, Bob!");
+    assert_eq!(Carol::greeting(), "HEY, CAROL!");
+    assert_eq!(Dave::greeting(), "OI, DAVE!");
+}
@@ -0,0 +1,136 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+trait Fields {
+    fn fields(&self) -> Vec<(&'static str, &'static str, String)>;
+}
+
+macro_rules! Fields {
+    (() $(pub)* struct $name:ident { $($body:tt)* }) => {
+        macro_attr_parse_named_fields! { (Fields! { @named $name, }), $($body)* }
+    };
+
+    (() $(pub)* struct $name:ident ($($body:tt)*);) => {
+        macro_attr_parse_tuple_fields! { (Fields! { @tuple $name, }), $($body)* }
+    };
+
+    (
+        @named $name:ident,
+        fields: ($((attrs: $_attrs:tt, vis: ($($vis:tt)*), name: $fname:ident, ty: $fty:ty),)*)
+    ) => {
+        impl Fields for $name {
+            fn fields(&self) -> Vec<(&'static str, &'static str, String)> {
+                vec![$(
+                    (stringify!($fname), stringify!($($vis)*), format!("{:?}", self.$fname as $fty)),
+                )*]
+            }
+        }
+    };
+
+    (
+        @tuple $name:ident,
+        fields: ($((attrs: $_attrs:tt, vis: ($($vis:tt)*), index: $idx:tt, ty: $fty:ty),)*)
+    ) => {
+        impl Fields for $name {
+            fn fields(&self) -> Vec<(&'static str, &'static str, String)> {
+                vec![$(
+                    (stringify!($idx), stringify!($($vis)*), format!("{:?}", self.$idx as $fty)),
+                )*]
+            }
+        }
+    };
+}
+
+trait Variants {
+    fn variants() -> Vec<(&'static str, &'static str, &'static str)>;
+}
+
+macro_rules! Variants {
+    (() $(pub)* enum $name:ident { $($body:tt)* }) => {
+        macro_attr_parse_variants! { (Variants! { @variants $name, }), $($body)* }
+    };
+
+    (
+        @variants $name:ident,
+        variants: ($((
+            attrs: $_attrs:tt, name: $vname:ident, kind: $kind:ident, body: $_body:tt,
+            discriminant: ($($disc:expr)*)
+        ),)*)
+    ) => {
+        impl Variants for $name {
+            fn variants() -> Vec<(&'static str, &'static str, &'static str)> {
+                vec![$((stringify!($vname), stringify!($kind), stringify!($($disc)*)),)*]
+            }
+        }
+    };
+}
+
+macro_attr! {
+    #[derive(Fields!)]
+    struct Named {
+        /// Documented.
+        pub a: i32,
+        #[allow(dead_code)]
+        b: u8
+    }
+}
+
+macro_attr! {
+    #[derive(Fields!)]
+    pub struct Tuple(pub i32, u8,);
+}
+
+macro_attr! {
+    #[allow(dead_code)]
+    #[derive(Variants!)]
+    enum Mixed {
+        /// Documented.
+        Unit,
+        Tuple(i32, Vec<(u8, u8)>),
+        Struct { a: i32, b: u8 },
+    }
+}
+
+macro_attr! {
+    #[allow(dead_code)]
+    #[derive(Variants!)]
+    enum Discriminants { Zero, Answer = 42, Next }
+}
+
+#[test]
+fn test_parse_named_fields() {
+    assert_eq!(Named { a: 1, b: 2 }.fields(), vec![
+        ("a", "pub", "1".to_owned()),
+        ("b", "", "2".to_owned()),
+    ]);
+}
+
+#[test]
+fn test_parse_tuple_fields() {
+    assert_eq!(Tuple(3, 4).fields(), vec![
+        ("0", "pub", "3".to_owned()),
+        ("1", "", "4".to_owned()),
+    ]);
+}
+
+#[test]
+fn test_parse_variants() {
+    assert_eq!(Mixed::variants(), vec![
+        ("Unit", "unit", ""),
+        ("Tuple", "tuple", ""),
+        ("Struct", "struct", ""),
+    ]);
+    assert_eq!(Discriminants::variants(), vec![
+        ("Zero", "unit", ""),
+        ("Answer", "unit", "42"),
+        ("Next", "unit", ""),
+    ]);
+}
@@ -0,0 +1,111 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+trait Describe {
+    fn kind() -> &'static str;
+    fn name() -> &'static str;
+    fn shape() -> &'static str;
+    fn ty_params() -> Vec<&'static str>;
+}
+
+macro_rules! Describe {
+    (() $($item:tt)*) => {
+        macro_attr_parse_item! { (Describe! { @parsed }), $($item)* }
+    };
+
+    (
+        @parsed
+        attrs: $_attrs:tt,
+        vis: $_vis:tt,
+        kind: $kind:ident,
+        name: $name:ident,
+        generics: $_generics:tt,
+        impl_generics: ($($impl_gen:tt)*),
+        ty_generics: ($($ty_gen:tt)*),
+        ty_params: ($($ty_params:ident,)*),
+        where: ($($where_:tt)*),
+        body: $body:tt
+    ) => {
+        impl<$($impl_gen)*> Describe for $name<$($ty_gen)*>
+        where $($where_)* {
+            fn kind() -> &'static str { stringify!($kind) }
+            fn name() -> &'static str { stringify!($name) }
+            fn shape() -> &'static str { Describe!(@shape $body) }
+            fn ty_params() -> Vec<&'static str> { vec![$(stringify!($ty_params)),*] }
+        }
+    };
+
+    (@shape ()) => { "unit" };
+    (@shape (($($_fields:tt)*))) => { "tuple" };
+    (@shape ({ $($_fields:tt)* })) => { "named" };
+}
+
+macro_attr! {
+    #[derive(Describe!)]
+    struct Unit;
+}
+
+macro_attr! {
+    #[allow(dead_code)]
+    #[derive(Clone, Describe!)]
+    pub struct Tuple<'a, T: 'a + Clone>(&'a T) where T: PartialEq;
+}
+
+macro_attr! {
+    #[allow(dead_code)]
+    #[derive(Describe!)]
+    struct Defaults<T: Into<Vec<(u8, u8)>> = Vec<(u8, u8)>, U = Option<Option<u8>>,> {
+        t: T,
+        u: U,
+    }
+}
+
+macro_attr! {
+    #[derive(Describe!)]
+    enum Either<L, R> where L: Clone, R: Clone { Left(L), Right(R) }
+}
+
+macro_attr! {
+    #[derive(Clone, Copy, Describe!)]
+    union Bits<T: Copy> { value: T, raw: u32 }
+}
+
+#[test]
+fn test_parse_item() {
+    assert_eq!(Unit::kind(), "struct");
+    assert_eq!(Unit::name(), "Unit");
+    assert_eq!(Unit::shape(), "unit");
+    assert_eq!(Unit::ty_params(), Vec::<&str>::new());
+
+    assert_eq!(<Tuple<u8>>::kind(), "struct");
+    assert_eq!(<Tuple<u8>>::name(), "Tuple");
+    assert_eq!(<Tuple<u8>>::shape(), "tuple");
+    assert_eq!(<Tuple<u8>>::ty_params(), vec!["T"]);
+
+    assert_eq!(<Defaults>::name(), "Defaults");
+    assert_eq!(<Defaults>::shape(), "named");
+    assert_eq!(<Defaults>::ty_params(), vec!["T", "U"]);
+
+    assert_eq!(<Either<u8, i8>>::kind(), "enum");
+    assert_eq!(<Either<u8, i8>>::shape(), "named");
+    assert_eq!(<Either<u8, i8>>::ty_params(), vec!["L", "R"]);
+
+    assert_eq!(<Bits<f32>>::kind(), "union");
+    assert_eq!(<Bits<f32>>::name(), "Bits");
+    assert_eq!(<Bits<f32>>::shape(), "named");
+    assert_eq!(<Bits<f32>>::ty_params(), vec!["T"]);
+
+    let bits: Bits<f32> = Bits { raw: 0x3f80_0000 };
+    assert_eq!(unsafe { bits.value }, 1.0f32);
+
+    let _ = (Unit, Tuple(&0u8).clone(), Either::Left::<u8, u8>(0), Either::Right::<u8, u8>(0));
+    let _: Defaults = Defaults { t: vec![], u: None };
+}
@@ -0,0 +1,28 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+/*!
+Makes sure that a heavily attributed item, the same as those in `benches/split_attrs.rs`, expands within a recursion limit well below the default of 128.
+*/
+#![recursion_limit = "48"]
+#![allow(dead_code)]
+
+#[macro_use] extern crate macro_attr;
+
+include!("common/heavy_items.rs");
+
+heavy_items! { Heavy }
+
+fn is_t23<T: T23>(_: T) -> bool { true }
+
+#[test]
+fn test_recursion_limit() {
+    assert_eq!(format!("{:?}", Heavy.clone()), "Heavy");
+    assert!(is_t23(Heavy));
+}
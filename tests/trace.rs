@@ -0,0 +1,99 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! Name {
+    (
+        ()
+        $(#[$($_attrs:tt)*])*
+        $vis:vis struct $name:ident $($_tail:tt)*
+    ) => {
+        impl $name {
+            pub fn name() -> &'static str {
+                stringify!($name)
+            }
+        }
+    };
+}
+
+macro_rules! Tag {
+    (
+        ($tag:expr)
+        $(#[$($_attrs:tt)*])*
+        $vis:vis struct $name:ident $($_tail:tt)*
+    ) => {
+        impl $name {
+            pub fn tag() -> &'static str {
+                $tag
+            }
+        }
+    };
+}
+
+macro_rules! rename_to {
+    (
+        ($new_name:ident), then $resume:tt,
+        $(#[$($attrs:tt)*])*
+        $vis:vis struct $_old_name:ident $($tail:tt)*
+    ) => {
+        macro_attr_callback! {
+            $resume,
+            $(#[$($attrs)*])*
+            $vis struct $new_name $($tail)*
+        }
+    };
+}
+
+macro_attr! {
+    #[macro_attr_trace(PLAIN_TRACE)]
+    #[derive(Clone, Name!)]
+    struct Plain;
+}
+
+macro_attr! {
+    #[derive(Debug)]
+    #[macro_attr_trace(pub RENAMED_TRACE)]
+    #[rename_to!(Renamed)]
+    #[derive(Tag!("tagged"))]
+    #[allow(dead_code)]
+    pub struct Original(i32);
+}
+
+macro_attr! {
+    #[derive(Name!)]
+    struct Untraced;
+}
+
+/// Strips whitespace, since how tokens are spaced out by `stringify!` varies between compiler versions.
+fn squash(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[test]
+fn test_trace() {
+    assert_eq!(Plain::name(), "Plain");
+    assert_eq!(squash(PLAIN_TRACE), squash("
+        derivation: Name!()
+        input: () struct Plain;
+        output: #[derive(Clone,)] struct Plain;
+    "));
+
+    assert_eq!(Renamed::tag(), "tagged");
+    assert_eq!(format!("{:?}", Renamed(1)), "Renamed(1)");
+    assert_eq!(squash(RENAMED_TRACE), squash("
+        macro attribute: #[rename_to!(Renamed)]
+        input: #[derive(Tag!(\"tagged\"))] #[allow(dead_code)] pub struct Original(i32);
+        derivation: Tag!(\"tagged\")
+        input: (\"tagged\") pub struct Renamed(i32);
+        output: #[derive(Debug,)] #[allow(dead_code)] pub struct Renamed(i32);
+    "));
+
+    assert_eq!(Untraced::name(), "Untraced");
+}
@@ -0,0 +1,68 @@
+/*
+Copyright ⓒ 2016 macro-attr contributors.
+
+Licensed under the MIT license (see LICENSE or <http://opensource.org
+/licenses/MIT>) or the Apache License, Version 2.0 (see LICENSE of
+<http://www.apache.org/licenses/LICENSE-2.0>), at your option. All
+files in the project carrying such notice may not be copied, modified,
+or distributed except according to those terms.
+*/
+#[macro_use] extern crate macro_attr;
+
+macro_rules! Name {
+    (() $vis:vis struct $name:ident $($_tail:tt)*) => {
+        impl $name {
+            $vis fn name() -> &'static str {
+                stringify!($name)
+            }
+        }
+    };
+}
+
+macro_rules! rename_to {
+    (
+        ($new_name:ident),
+        then $cb:tt,
+        $(#[$($attrs:tt)*])*
+        $vis:vis struct $_old_name:ident $($tail:tt)*
+    ) => {
+        macro_attr_callback! {
+            $cb,
+            $(#[$($attrs)*])*
+            $vis struct $new_name $($tail)*
+        }
+    };
+}
+
+mod outer {
+    pub mod inner {
+        macro_attr! {
+            #[derive(Debug, Name!)]
+            pub(crate) struct Crate(pub(crate) u8);
+        }
+
+        macro_attr! {
+            #[derive(Debug, Name!)]
+            #[rename_to!(Renamed)]
+            pub(super) struct Super { pub(super) field: u8 }
+        }
+
+        macro_attr! {
+            #[derive(Debug, Name!)]
+            pub(in outer) struct InPath;
+        }
+    }
+
+    pub fn check() {
+        assert_eq!(inner::Renamed::name(), "Renamed");
+        assert_eq!(inner::Renamed { field: 1 }.field, 1);
+        assert_eq!(inner::InPath::name(), "InPath");
+    }
+}
+
+#[test]
+fn test_visibility() {
+    assert_eq!(outer::inner::Crate::name(), "Crate");
+    assert_eq!(outer::inner::Crate(42).0, 42);
+    outer::check();
+}